@@ -0,0 +1 @@
+ALTER TABLE transactions ALTER COLUMN data TYPE json USING data::json;
@@ -0,0 +1,2 @@
+DROP INDEX transactions_idempotency_key_idx;
+ALTER TABLE transactions DROP COLUMN idempotency_key;
@@ -0,0 +1,3 @@
+DROP INDEX token_ownership_changes_token_address_idx;
+DROP TABLE token_ownership_changes;
+ALTER TABLE fungible_tokens DROP COLUMN minter_addresses;
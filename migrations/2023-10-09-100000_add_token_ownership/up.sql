@@ -0,0 +1,11 @@
+ALTER TABLE fungible_tokens ADD COLUMN minter_addresses TEXT NOT NULL DEFAULT '[]';
+
+CREATE TABLE token_ownership_changes (
+    id SERIAL PRIMARY KEY,
+    token_address VARCHAR(42) NOT NULL,
+    previous_owner_address VARCHAR(42) NOT NULL,
+    new_owner_address VARCHAR(42) NOT NULL,
+    block_number INTEGER NOT NULL,
+    transaction_hash VARCHAR(66) NOT NULL
+);
+CREATE INDEX token_ownership_changes_token_address_idx ON token_ownership_changes (token_address);
@@ -0,0 +1,3 @@
+ALTER TABLE fungible_tokens ADD COLUMN max_supply BIGINT;
+ALTER TABLE fungible_tokens ADD COLUMN mintable BOOLEAN NOT NULL DEFAULT TRUE;
+ALTER TABLE fungible_tokens ADD COLUMN burnable BOOLEAN NOT NULL DEFAULT TRUE;
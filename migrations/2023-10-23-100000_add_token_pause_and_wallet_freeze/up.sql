@@ -0,0 +1,2 @@
+ALTER TABLE fungible_tokens ADD COLUMN paused BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE wallets ADD COLUMN frozen BOOLEAN NOT NULL DEFAULT FALSE;
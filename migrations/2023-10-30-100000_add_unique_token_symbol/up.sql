@@ -0,0 +1,15 @@
+-- Refuses to run while tokens share a symbol whatever its case, see the upgrade notes of the README
+DO $$
+DECLARE
+    duplicate_symbol VARCHAR;
+BEGIN
+    SELECT LOWER(symbol) INTO duplicate_symbol FROM fungible_tokens
+    GROUP BY LOWER(symbol)
+    HAVING COUNT(*) > 1
+    LIMIT 1;
+    IF duplicate_symbol IS NOT NULL THEN
+        RAISE EXCEPTION 'Cannot make the token symbols unique: several tokens have the symbol % whatever its case, rename all of them but one', duplicate_symbol;
+    END IF;
+END $$;
+
+CREATE UNIQUE INDEX fungible_tokens_symbol_idx ON fungible_tokens (LOWER(symbol));
@@ -0,0 +1,18 @@
+-- Restores the addresses as they were before the normalization, the rows stored since stay lowercase
+UPDATE blocks SET
+    miner_address = COALESCE((SELECT original_value FROM address_normalization_backup WHERE table_name = 'blocks' AND column_name = 'miner_address' AND row_key = CAST(blocks.block_number AS VARCHAR)), miner_address);
+UPDATE transactions SET
+    from_address = COALESCE((SELECT original_value FROM address_normalization_backup WHERE table_name = 'transactions' AND column_name = 'from_address' AND row_key = CAST(transactions.id AS VARCHAR)), from_address),
+    to_address = COALESCE((SELECT original_value FROM address_normalization_backup WHERE table_name = 'transactions' AND column_name = 'to_address' AND row_key = CAST(transactions.id AS VARCHAR)), to_address);
+UPDATE fungible_tokens SET
+    address = COALESCE((SELECT original_value FROM address_normalization_backup WHERE table_name = 'fungible_tokens' AND column_name = 'address' AND row_key = fungible_tokens.address), address),
+    owner_address = COALESCE((SELECT original_value FROM address_normalization_backup WHERE table_name = 'fungible_tokens' AND column_name = 'owner_address' AND row_key = fungible_tokens.address), owner_address),
+    minter_addresses = COALESCE((SELECT original_value FROM address_normalization_backup WHERE table_name = 'fungible_tokens' AND column_name = 'minter_addresses' AND row_key = fungible_tokens.address), minter_addresses);
+UPDATE wallets SET
+    address = COALESCE((SELECT original_value FROM address_normalization_backup WHERE table_name = 'wallets' AND column_name = 'address' AND row_key = wallets.address || ':' || wallets.token_address), address),
+    token_address = COALESCE((SELECT original_value FROM address_normalization_backup WHERE table_name = 'wallets' AND column_name = 'token_address' AND row_key = wallets.address || ':' || wallets.token_address), token_address);
+UPDATE token_ownership_changes SET
+    token_address = COALESCE((SELECT original_value FROM address_normalization_backup WHERE table_name = 'token_ownership_changes' AND column_name = 'token_address' AND row_key = CAST(token_ownership_changes.id AS VARCHAR)), token_address),
+    previous_owner_address = COALESCE((SELECT original_value FROM address_normalization_backup WHERE table_name = 'token_ownership_changes' AND column_name = 'previous_owner_address' AND row_key = CAST(token_ownership_changes.id AS VARCHAR)), previous_owner_address),
+    new_owner_address = COALESCE((SELECT original_value FROM address_normalization_backup WHERE table_name = 'token_ownership_changes' AND column_name = 'new_owner_address' AND row_key = CAST(token_ownership_changes.id AS VARCHAR)), new_owner_address);
+DROP TABLE address_normalization_backup;
@@ -0,0 +1,2 @@
+DROP INDEX vesting_schedules_beneficiary_address_idx;
+DROP TABLE vesting_schedules;
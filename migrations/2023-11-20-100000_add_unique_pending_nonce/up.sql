@@ -0,0 +1,13 @@
+-- Transactions stored before nonces all have nonce 0, the pending ones sharing a sender and nonce are renumbered after the highest nonce of their sender
+UPDATE transactions SET nonce = renumbered.nonce
+FROM (
+    SELECT duplicates.id, (SELECT MAX(nonce) FROM transactions AS sender_transactions WHERE sender_transactions.from_address = duplicates.from_address)
+        + ROW_NUMBER() OVER (PARTITION BY duplicates.from_address ORDER BY duplicates.id) AS nonce
+    FROM transactions AS duplicates
+    WHERE duplicates.status = 'RAW'
+    AND duplicates.id NOT IN (SELECT MIN(id) FROM transactions WHERE status = 'RAW' GROUP BY from_address, nonce)
+) AS renumbered
+WHERE transactions.id = renumbered.id;
+
+-- A nonce is held by a single pending transaction of its sender, even when submissions race
+CREATE UNIQUE INDEX transactions_pending_sender_nonce_idx ON transactions (from_address, nonce) WHERE status = 'RAW';
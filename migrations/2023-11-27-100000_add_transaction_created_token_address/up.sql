@@ -0,0 +1,8 @@
+ALTER TABLE transactions ADD COLUMN created_token_address VARCHAR(42);
+
+-- Transactions stored before nonces all have nonce 0, so the token of a mined INIT_FT is found through its creating transaction instead of being derived
+UPDATE transactions SET created_token_address = fungible_tokens.address
+FROM fungible_tokens
+WHERE fungible_tokens.transaction_hash = transactions.transaction_hash
+AND transactions.transaction_type = 'INIT_FT'
+AND transactions.status = 'SUCCESS';
@@ -0,0 +1,8 @@
+CREATE TABLE events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    block_number INTEGER,
+    transaction_hash VARCHAR(66) NOT NULL,
+    value INTEGER NOT NULL,
+    timestamp TIMESTAMP,
+    data TEXT
+);
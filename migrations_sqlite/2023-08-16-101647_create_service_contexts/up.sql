@@ -0,0 +1,4 @@
+CREATE TABLE service_contexts (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  maintenance BOOLEAN NOT NULL DEFAULT FALSE
+);
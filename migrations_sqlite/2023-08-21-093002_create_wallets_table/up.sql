@@ -0,0 +1,8 @@
+CREATE TABLE wallets (
+    address VARCHAR(42) NOT NULL,
+    token_address VARCHAR(42) NOT NULL,
+    balance BIGINT NOT NULL,
+    block_number INTEGER NOT NULL,
+    transaction_hash VARCHAR(66) NOT NULL,
+    PRIMARY KEY (address, token_address)
+);
@@ -0,0 +1 @@
+DROP TABLE fungible_tokens;
@@ -0,0 +1,3 @@
+DROP INDEX transactions_sender_nonce_idx;
+ALTER TABLE transactions DROP COLUMN nonce;
+ALTER TABLE transactions DROP COLUMN fee;
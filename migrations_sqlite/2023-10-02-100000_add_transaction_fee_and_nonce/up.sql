@@ -0,0 +1,3 @@
+ALTER TABLE transactions ADD COLUMN fee BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE transactions ADD COLUMN nonce BIGINT NOT NULL DEFAULT 0;
+CREATE INDEX transactions_sender_nonce_idx ON transactions (from_address, nonce);
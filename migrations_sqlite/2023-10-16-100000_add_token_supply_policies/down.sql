@@ -0,0 +1,3 @@
+ALTER TABLE fungible_tokens DROP COLUMN burnable;
+ALTER TABLE fungible_tokens DROP COLUMN mintable;
+ALTER TABLE fungible_tokens DROP COLUMN max_supply;
@@ -0,0 +1,2 @@
+ALTER TABLE wallets DROP COLUMN frozen;
+ALTER TABLE fungible_tokens DROP COLUMN paused;
@@ -0,0 +1 @@
+DROP INDEX fungible_tokens_symbol_idx;
@@ -0,0 +1,10 @@
+-- Refuses to run while tokens share a symbol whatever its case, see the upgrade notes of the README
+CREATE TEMPORARY TABLE token_symbol_check (
+    problem VARCHAR NOT NULL,
+    CONSTRAINT "Cannot make the token symbols unique: several tokens have the same symbol whatever its case, rename all of them but one" CHECK (problem <> 'duplicate symbol')
+);
+INSERT INTO token_symbol_check
+    SELECT 'duplicate symbol' FROM fungible_tokens GROUP BY LOWER(symbol) HAVING COUNT(*) > 1;
+DROP TABLE token_symbol_check;
+
+CREATE UNIQUE INDEX fungible_tokens_symbol_idx ON fungible_tokens (LOWER(symbol));
@@ -0,0 +1,65 @@
+-- Refuses to normalize addresses that would stay invalid or make two wallets or tokens collide, see the upgrade notes of the README
+CREATE TEMPORARY TABLE address_normalization_check (
+    problem VARCHAR NOT NULL,
+    CONSTRAINT "Cannot normalize addresses: a stored address is not 0x followed by 40 hex digits, fix or delete the rows holding it" CHECK (problem <> 'invalid'),
+    CONSTRAINT "Cannot normalize addresses: several wallets only differ by the case of their addresses, merge their balances into one" CHECK (problem <> 'colliding wallet'),
+    CONSTRAINT "Cannot normalize addresses: several tokens only differ by the case of their address, keep only one of them" CHECK (problem <> 'colliding token')
+);
+INSERT INTO address_normalization_check
+    SELECT 'invalid' FROM (
+        SELECT miner_address AS address FROM blocks
+        UNION ALL SELECT from_address FROM transactions
+        UNION ALL SELECT to_address FROM transactions WHERE to_address <> ''
+        UNION ALL SELECT address FROM fungible_tokens
+        UNION ALL SELECT owner_address FROM fungible_tokens
+        UNION ALL SELECT minters.value FROM fungible_tokens, json_each(fungible_tokens.minter_addresses) AS minters
+        UNION ALL SELECT address FROM wallets
+        UNION ALL SELECT token_address FROM wallets
+        UNION ALL SELECT token_address FROM token_ownership_changes
+        UNION ALL SELECT previous_owner_address FROM token_ownership_changes
+        UNION ALL SELECT new_owner_address FROM token_ownership_changes
+    ) AS stored_addresses
+    WHERE LENGTH(address) <> 42 OR LOWER(address) NOT GLOB '0x*' OR SUBSTR(LOWER(address), 3) GLOB '*[^0-9a-f]*';
+INSERT INTO address_normalization_check
+    SELECT 'colliding wallet' FROM wallets GROUP BY LOWER(address), LOWER(token_address) HAVING COUNT(*) > 1;
+INSERT INTO address_normalization_check
+    SELECT 'colliding token' FROM fungible_tokens GROUP BY LOWER(address) HAVING COUNT(*) > 1;
+DROP TABLE address_normalization_check;
+
+-- Keeps the addresses the normalization changes so that it can be reverted
+CREATE TABLE address_normalization_backup (
+    table_name VARCHAR NOT NULL,
+    row_key VARCHAR NOT NULL,
+    column_name VARCHAR NOT NULL,
+    original_value TEXT NOT NULL,
+    PRIMARY KEY (table_name, row_key, column_name)
+);
+INSERT INTO address_normalization_backup
+    SELECT 'blocks', CAST(block_number AS VARCHAR), 'miner_address', miner_address FROM blocks WHERE miner_address <> LOWER(miner_address);
+INSERT INTO address_normalization_backup
+    SELECT 'transactions', CAST(id AS VARCHAR), 'from_address', from_address FROM transactions WHERE from_address <> LOWER(from_address);
+INSERT INTO address_normalization_backup
+    SELECT 'transactions', CAST(id AS VARCHAR), 'to_address', to_address FROM transactions WHERE to_address <> LOWER(to_address) OR to_address = '';
+INSERT INTO address_normalization_backup
+    SELECT 'fungible_tokens', LOWER(address), 'address', address FROM fungible_tokens WHERE address <> LOWER(address);
+INSERT INTO address_normalization_backup
+    SELECT 'fungible_tokens', LOWER(address), 'owner_address', owner_address FROM fungible_tokens WHERE owner_address <> LOWER(owner_address);
+INSERT INTO address_normalization_backup
+    SELECT 'fungible_tokens', LOWER(address), 'minter_addresses', minter_addresses FROM fungible_tokens WHERE minter_addresses <> LOWER(minter_addresses);
+INSERT INTO address_normalization_backup
+    SELECT 'wallets', LOWER(address) || ':' || LOWER(token_address), 'address', address FROM wallets WHERE address <> LOWER(address);
+INSERT INTO address_normalization_backup
+    SELECT 'wallets', LOWER(address) || ':' || LOWER(token_address), 'token_address', token_address FROM wallets WHERE token_address <> LOWER(token_address);
+INSERT INTO address_normalization_backup
+    SELECT 'token_ownership_changes', CAST(id AS VARCHAR), 'token_address', token_address FROM token_ownership_changes WHERE token_address <> LOWER(token_address);
+INSERT INTO address_normalization_backup
+    SELECT 'token_ownership_changes', CAST(id AS VARCHAR), 'previous_owner_address', previous_owner_address FROM token_ownership_changes WHERE previous_owner_address <> LOWER(previous_owner_address);
+INSERT INTO address_normalization_backup
+    SELECT 'token_ownership_changes', CAST(id AS VARCHAR), 'new_owner_address', new_owner_address FROM token_ownership_changes WHERE new_owner_address <> LOWER(new_owner_address);
+
+UPDATE blocks SET miner_address = LOWER(miner_address);
+UPDATE transactions SET from_address = LOWER(from_address), to_address = LOWER(to_address);
+UPDATE transactions SET to_address = '0x0000000000000000000000000000000000000000' WHERE to_address = '';
+UPDATE fungible_tokens SET address = LOWER(address), owner_address = LOWER(owner_address), minter_addresses = LOWER(minter_addresses);
+UPDATE wallets SET address = LOWER(address), token_address = LOWER(token_address);
+UPDATE token_ownership_changes SET token_address = LOWER(token_address), previous_owner_address = LOWER(previous_owner_address), new_owner_address = LOWER(new_owner_address);
@@ -0,0 +1,15 @@
+CREATE TABLE vesting_schedules (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    token_address VARCHAR(42) NOT NULL,
+    grantor_address VARCHAR(42) NOT NULL,
+    beneficiary_address VARCHAR(42) NOT NULL,
+    unit VARCHAR(16) NOT NULL,
+    start BIGINT NOT NULL,
+    cliff BIGINT NOT NULL,
+    duration BIGINT NOT NULL,
+    total_amount BIGINT NOT NULL,
+    claimed_amount BIGINT NOT NULL DEFAULT 0,
+    block_number INTEGER NOT NULL,
+    transaction_hash VARCHAR(66) NOT NULL
+);
+CREATE INDEX vesting_schedules_beneficiary_address_idx ON vesting_schedules (beneficiary_address, token_address);
@@ -0,0 +1 @@
+DROP INDEX transactions_pending_sender_nonce_idx;
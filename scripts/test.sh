@@ -1,2 +1 @@
-diesel database reset
-RUST_TEST_THREADS=1 cargo test
\ No newline at end of file
+cargo test
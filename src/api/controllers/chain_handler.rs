@@ -0,0 +1,21 @@
+use crate::api::dto::chain::ChainInfoDTO;
+use crate::domain::error::ApiError;
+use crate::domain::services::chain::ChainService;
+use actix_web::{web, Result};
+
+#[utoipa::path(
+    get,
+    path = "/api/chain",
+    tag = "Chain",
+    responses(
+        (status = 200, description = "Chain information retrieved successfully", body = ChainInfoDTO),
+        (status = 400, description = "Bad Request"),
+        (status = 500, description = "Internal Server Error"),
+        (status = 503, description = "Service Unavailable"),
+        (status = 429, description = "Too Many Requests"),
+    )
+)]
+pub async fn get_chain_handler(chain_service: web::Data<dyn ChainService>) -> Result<web::Json<ChainInfoDTO>, ApiError> {
+    let chain_info = chain_service.info().await?;
+    Ok(web::Json(chain_info.into()))
+}
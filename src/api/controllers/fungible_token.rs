@@ -1,5 +1,6 @@
-use crate::api::dto::fungible_token::FungibleTokenDTO;
+use crate::api::dto::fungible_token::{FungibleTokenDTO, OwnershipChangeDTO};
 use crate::domain::error::ApiError;
+use crate::domain::models::address::Address;
 use crate::domain::repositories::fungible_token::FungibleTokenQueryParams;
 use crate::domain::repositories::repository::ResultPaging;
 use crate::domain::services::fungible_token::FungibleTokenService;
@@ -38,6 +39,48 @@ pub async fn list_ft_handler(ft_service: web::Data<dyn FungibleTokenService>, pa
     )
 )]
 pub async fn get_ft_handler(ft_service: web::Data<dyn FungibleTokenService>, params: web::Path<String>) -> Result<web::Json<FungibleTokenDTO>, ApiError> {
-    let ft = ft_service.get(&params.into_inner()).await?;
+    let token_address: Address = params.into_inner().parse()?;
+    let ft = ft_service.get(&token_address).await?;
     Ok(web::Json(ft.into()))
 }
+
+#[utoipa::path(
+    get,
+    path = "/api/fts/by-symbol/{symbol}",
+    tag = "Fungible Token",
+    params(
+        ("symbol", description = "Fungible Token symbol, matched case-insensitively")
+    ),
+    responses(
+        (status = 200, description = "Fungible Token found successfully", body = FungibleTokenDTO),
+        (status = 400, description = "Bad Request"),
+        (status = 500, description = "Internal Server Error"),
+        (status = 503, description = "Service Unavailable"),
+        (status = 429, description = "Too Many Requests"),
+    )
+)]
+pub async fn get_ft_by_symbol_handler(ft_service: web::Data<dyn FungibleTokenService>, params: web::Path<String>) -> Result<web::Json<FungibleTokenDTO>, ApiError> {
+    let ft = ft_service.get_by_symbol(&params.into_inner()).await?;
+    Ok(web::Json(ft.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/fts/{address}/ownership-history",
+    tag = "Fungible Token",
+    params(
+        ("address", description = "Fungible Token address")
+    ),
+    responses(
+        (status = 200, description = "Ownership changes of the Fungible Token, oldest first", body = [OwnershipChangeDTO]),
+        (status = 400, description = "Bad Request"),
+        (status = 500, description = "Internal Server Error"),
+        (status = 503, description = "Service Unavailable"),
+        (status = 429, description = "Too Many Requests"),
+    )
+)]
+pub async fn get_ft_ownership_history_handler(ft_service: web::Data<dyn FungibleTokenService>, params: web::Path<String>) -> Result<web::Json<Vec<OwnershipChangeDTO>>, ApiError> {
+    let token_address: Address = params.into_inner().parse()?;
+    let ownership_changes = ft_service.ownership_history(&token_address).await?;
+    Ok(web::Json(ownership_changes.into_iter().map(|change| change.into()).collect()))
+}
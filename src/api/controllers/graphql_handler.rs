@@ -0,0 +1,26 @@
+use crate::api::graphql::LedgerSchema;
+use actix_web::{web, HttpResponse};
+use async_graphql::http::{playground_source, GraphQLPlaygroundConfig};
+use async_graphql_actix_web::{GraphQLRequest, GraphQLResponse};
+
+pub const GRAPHQL_PATH: &str = "/graphql";
+
+#[utoipa::path(
+    post,
+    path = "/graphql",
+    tag = "GraphQL",
+    request_body(content = String, description = "A GraphQL request, see the schema in the playground", content_type = "application/json"),
+    responses(
+        (status = 200, description = "The GraphQL response, errors included", body = String, content_type = "application/json"),
+        (status = 503, description = "Service Unavailable"),
+    )
+)]
+pub async fn graphql_handler(schema: web::Data<LedgerSchema>, request: GraphQLRequest) -> GraphQLResponse {
+    schema.execute(request.into_inner()).await.into()
+}
+
+pub async fn graphql_playground_handler() -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(playground_source(GraphQLPlaygroundConfig::new(GRAPHQL_PATH)))
+}
@@ -0,0 +1,51 @@
+use crate::api::dto::health::{LivenessDTO, NodeInfoDTO, ReadinessDTO, STATUS_UP};
+use crate::domain::error::ApiError;
+use crate::domain::services::health::HealthService;
+use actix_web::{web, HttpResponse, Result};
+
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    tag = "Health",
+    responses(
+        (status = 200, description = "The process is alive", body = LivenessDTO),
+    )
+)]
+pub async fn liveness_handler() -> web::Json<LivenessDTO> {
+    web::Json(LivenessDTO { status: STATUS_UP.to_string() })
+}
+
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "Health",
+    responses(
+        (status = 200, description = "The node is ready to serve traffic", body = ReadinessDTO),
+        (status = 503, description = "At least one check failed", body = ReadinessDTO),
+    )
+)]
+pub async fn readiness_handler(health_service: web::Data<dyn HealthService>) -> HttpResponse {
+    let report = health_service.readiness().await;
+    let healthy = report.healthy;
+    let readiness: ReadinessDTO = report.into();
+    if healthy {
+        HttpResponse::Ok().json(readiness)
+    } else {
+        HttpResponse::ServiceUnavailable().json(readiness)
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/info",
+    tag = "Health",
+    responses(
+        (status = 200, description = "Build and chain information retrieved successfully", body = NodeInfoDTO),
+        (status = 400, description = "Bad Request"),
+        (status = 500, description = "Internal Server Error"),
+    )
+)]
+pub async fn info_handler(health_service: web::Data<dyn HealthService>) -> Result<web::Json<NodeInfoDTO>, ApiError> {
+    let node_info = health_service.info().await?;
+    Ok(web::Json(node_info.into()))
+}
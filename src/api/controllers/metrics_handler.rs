@@ -0,0 +1,18 @@
+use crate::domain::error::ApiError;
+use crate::domain::services::metrics::MetricsService;
+use actix_web::{web, HttpResponse, Result};
+
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "Health",
+    responses(
+        (status = 200, description = "Metrics in the Prometheus text format", body = String, content_type = "text/plain"),
+        (status = 400, description = "Bad Request"),
+        (status = 500, description = "Internal Server Error"),
+    )
+)]
+pub async fn metrics_handler(metrics_service: web::Data<dyn MetricsService>) -> Result<HttpResponse, ApiError> {
+    let metrics = metrics_service.render().await?;
+    Ok(HttpResponse::Ok().content_type("text/plain; version=0.0.4").body(metrics))
+}
@@ -1,5 +1,13 @@
 pub mod block_handler;
+pub mod chain_handler;
 pub mod fungible_token;
+pub mod graphql_handler;
+pub mod health_handler;
+pub mod metrics_handler;
+pub mod rpc_handler;
 pub mod service_context_handler;
+pub mod stream_handler;
+pub mod subscription_handler;
 pub mod transaction_handler;
+pub mod vesting_handler;
 pub mod wallet_handler;
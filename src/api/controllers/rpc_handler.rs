@@ -0,0 +1,162 @@
+use std::sync::Arc;
+
+use crate::api::dto::block::BlockDTO;
+use crate::api::dto::fungible_token::FungibleTokenDTO;
+use crate::api::dto::rpc::{
+    JsonRpcErrorDTO, JsonRpcRequestDTO, JsonRpcResponseDTO, INTERNAL_ERROR, INVALID_PARAMS, INVALID_REQUEST, JSON_RPC_VERSION, MAX_RPC_BATCH_SIZE, METHOD_NOT_FOUND, PARSE_ERROR,
+};
+use crate::api::dto::transaction::{CreateTransactionDTO, TransactionDTO};
+use crate::api::dto::wallet::WalletDTO;
+use crate::domain::error::CommonError;
+use crate::domain::models::address::Address;
+use crate::domain::repositories::block::BlockQueryParams;
+use crate::domain::services::block::BlockService;
+use crate::domain::services::fungible_token::FungibleTokenService;
+use crate::domain::services::transaction::TransactionService;
+use crate::domain::services::wallet::WalletService;
+use actix_web::web::Bytes;
+use actix_web::{web, HttpResponse};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+#[utoipa::path(
+    post,
+    path = "/rpc",
+    tag = "JSON-RPC",
+    request_body(content = JsonRpcRequestDTO, description = "A JSON-RPC 2.0 call, or an array of calls to run as a batch"),
+    responses(
+        (status = 200, description = "The response of the call, or the array of responses of a batch", body = JsonRpcResponseDTO),
+        (status = 204, description = "Only notifications were sent"),
+        (status = 503, description = "Service Unavailable"),
+    )
+)]
+pub async fn rpc_handler(
+    body: Bytes,
+    block_service: web::Data<dyn BlockService>,
+    transaction_service: web::Data<dyn TransactionService>,
+    wallet_service: web::Data<dyn WalletService>,
+    fungible_token_service: web::Data<dyn FungibleTokenService>,
+) -> HttpResponse {
+    let rpc = RpcServices {
+        block_service: block_service.into_inner(),
+        transaction_service: transaction_service.into_inner(),
+        wallet_service: wallet_service.into_inner(),
+        fungible_token_service: fungible_token_service.into_inner(),
+    };
+
+    let payload: Value = match serde_json::from_slice(&body) {
+        Ok(payload) => payload,
+        Err(e) => return HttpResponse::Ok().json(JsonRpcResponseDTO::failure(Value::Null, JsonRpcErrorDTO::new(PARSE_ERROR, format!("Parse error: {}", e)))),
+    };
+
+    match payload {
+        Value::Array(calls) if calls.is_empty() => HttpResponse::Ok().json(JsonRpcResponseDTO::failure(Value::Null, JsonRpcErrorDTO::new(INVALID_REQUEST, "Invalid Request: empty batch"))),
+        Value::Array(calls) if calls.len() > MAX_RPC_BATCH_SIZE => HttpResponse::Ok().json(JsonRpcResponseDTO::failure(
+            Value::Null,
+            JsonRpcErrorDTO::new(INVALID_REQUEST, format!("Invalid Request: a batch holds at most {} calls", MAX_RPC_BATCH_SIZE)),
+        )),
+        Value::Array(calls) => {
+            // Calls of a batch run in order, so a transaction sent by one call is visible to the next ones
+            let mut responses = Vec::with_capacity(calls.len());
+            for call in calls {
+                if let Some(response) = rpc.handle_call(call).await {
+                    responses.push(response);
+                }
+            }
+            if responses.is_empty() {
+                HttpResponse::NoContent().finish()
+            } else {
+                HttpResponse::Ok().json(responses)
+            }
+        }
+        call => match rpc.handle_call(call).await {
+            Some(response) => HttpResponse::Ok().json(response),
+            None => HttpResponse::NoContent().finish(),
+        },
+    }
+}
+
+struct RpcServices {
+    block_service: Arc<dyn BlockService>,
+    transaction_service: Arc<dyn TransactionService>,
+    wallet_service: Arc<dyn WalletService>,
+    fungible_token_service: Arc<dyn FungibleTokenService>,
+}
+
+impl RpcServices {
+    // Returns None for notifications, i.e. calls without an id
+    async fn handle_call(&self, call: Value) -> Option<JsonRpcResponseDTO> {
+        let is_notification = call.is_object() && call.get("id").is_none();
+        // An invalid request is answered with its id whenever it is a string or a number
+        let readable_id = call.get("id").filter(|id| id.is_string() || id.is_number()).cloned().unwrap_or(Value::Null);
+        let request = match serde_json::from_value::<JsonRpcRequestDTO>(call) {
+            Ok(request) if request.jsonrpc == JSON_RPC_VERSION => request,
+            _ => return Some(JsonRpcResponseDTO::failure(readable_id, JsonRpcErrorDTO::new(INVALID_REQUEST, "Invalid Request"))),
+        };
+
+        let result = self.dispatch(&request.method, &request.params).await;
+        if is_notification {
+            return None;
+        }
+        let id = request.id.unwrap_or(Value::Null);
+        Some(match result {
+            Ok(result) => JsonRpcResponseDTO::success(id, result),
+            Err(error) => JsonRpcResponseDTO::failure(id, error),
+        })
+    }
+
+    async fn dispatch(&self, method: &str, params: &Option<Value>) -> Result<Value, JsonRpcErrorDTO> {
+        match method {
+            "chain_blockNumber" => {
+                let latest_block = self.block_service.list(BlockQueryParams { limit: Some(1), offset: Some(0) }).await.map_err(server_error)?;
+                to_result(latest_block.items.first().map(|block| block.block_number).unwrap_or_default())
+            }
+            "chain_getBlockByNumber" => {
+                let block_number: i32 = param(params, 0, "block_number")?;
+                let block: BlockDTO = self.block_service.get(block_number).await.map_err(server_error)?.into();
+                to_result(block)
+            }
+            "chain_getTransactionByHash" => {
+                let transaction_hash: String = param(params, 0, "transaction_hash")?;
+                let transaction: TransactionDTO = self.transaction_service.get(&transaction_hash).await.map_err(server_error)?.into();
+                to_result(transaction)
+            }
+            "ledger_sendTransaction" => {
+                let transaction: CreateTransactionDTO = param(params, 0, "transaction")?;
+                let transaction: TransactionDTO = self.transaction_service.create(transaction.into()).await.map_err(server_error)?.into();
+                to_result(transaction)
+            }
+            "ledger_getBalance" => {
+                let wallet_address: Address = param(params, 0, "wallet_address")?;
+                let token_address: Address = param(params, 1, "token_address")?;
+                let wallet: WalletDTO = self.wallet_service.get(&wallet_address, &token_address).await.map_err(server_error)?.into();
+                to_result(wallet)
+            }
+            "ledger_getToken" => {
+                let token_address: Address = param(params, 0, "token_address")?;
+                let fungible_token: FungibleTokenDTO = self.fungible_token_service.get(&token_address).await.map_err(server_error)?.into();
+                to_result(fungible_token)
+            }
+            _ => Err(JsonRpcErrorDTO::new(METHOD_NOT_FOUND, format!("Method not found: {}", method))),
+        }
+    }
+}
+
+// Reads a parameter passed either by position or by name
+fn param<T: DeserializeOwned>(params: &Option<Value>, position: usize, name: &str) -> Result<T, JsonRpcErrorDTO> {
+    let value = match params {
+        Some(Value::Array(values)) => values.get(position).cloned(),
+        Some(Value::Object(values)) => values.get(name).cloned(),
+        _ => None,
+    };
+    serde_json::from_value(value.unwrap_or(Value::Null)).map_err(|e| JsonRpcErrorDTO::new(INVALID_PARAMS, format!("Invalid params: {}: {}", name, e)))
+}
+
+fn to_result<T: Serialize>(result: T) -> Result<Value, JsonRpcErrorDTO> {
+    serde_json::to_value(result).map_err(|e| JsonRpcErrorDTO::new(INTERNAL_ERROR, format!("Internal error: {}", e)))
+}
+
+fn server_error(error: CommonError) -> JsonRpcErrorDTO {
+    error.into()
+}
@@ -0,0 +1,209 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::api::dto::block::BlockDTO;
+use crate::api::dto::stream::{sse_frame, TransactionStreamQueryParams, LAST_EVENT_ID_HEADER};
+use crate::api::dto::transaction::TransactionDTO;
+use crate::domain::error::{ApiError, CommonError};
+use crate::domain::models::address::Address;
+use crate::domain::models::block::Block;
+use crate::domain::models::ledger_event::LedgerEvent;
+use crate::domain::models::transaction::Transaction;
+use crate::domain::services::block::BlockService;
+use crate::domain::services::event_bus::EventBus;
+use crate::domain::services::transaction::TransactionService;
+use actix_web::http::header::CACHE_CONTROL;
+use actix_web::rt::time::{interval, Interval};
+use actix_web::web::Bytes;
+use actix_web::{web, HttpRequest, HttpResponse, Result};
+use futures_util::stream;
+use tokio::sync::broadcast::Receiver;
+use tracing::warn;
+
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+const REPLAY_PAGE_SIZE: i64 = 100;
+
+#[utoipa::path(
+    get,
+    path = "/api/stream/blocks",
+    tag = "Subscriptions",
+    params(
+        ("Last-Event-ID" = Option<i32>, Header, description = "Replays the blocks mined after this block number before streaming new ones")
+    ),
+    responses(
+        (status = 200, description = "Server-Sent Events stream of `block` events, each identified by its block number", body = BlockDTO, content_type = "text/event-stream"),
+        (status = 400, description = "Bad Request"),
+    )
+)]
+pub async fn block_stream_handler(
+    req: HttpRequest,
+    event_bus: web::Data<dyn EventBus>,
+    block_service: web::Data<dyn BlockService>,
+    transaction_service: web::Data<dyn TransactionService>,
+) -> Result<HttpResponse, ApiError> {
+    let feed = Feed::Blocks;
+    event_stream_response(feed, &req, event_bus.into_inner(), block_service.into_inner(), transaction_service.into_inner())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/stream/transactions",
+    tag = "Subscriptions",
+    params(
+        ("address" = Option<String>, Query, description = "Only stream the transactions sent from or to this address"),
+        ("Last-Event-ID" = Option<i32>, Header, description = "Replays the transactions mined after this block number before streaming new ones")
+    ),
+    responses(
+        (status = 200, description = "Server-Sent Events stream of `transaction` events for mined transactions, each identified by its block number", body = TransactionDTO, content_type = "text/event-stream"),
+        (status = 400, description = "Bad Request"),
+    )
+)]
+pub async fn transaction_stream_handler(
+    req: HttpRequest,
+    params: web::Query<TransactionStreamQueryParams>,
+    event_bus: web::Data<dyn EventBus>,
+    block_service: web::Data<dyn BlockService>,
+    transaction_service: web::Data<dyn TransactionService>,
+) -> Result<HttpResponse, ApiError> {
+    let feed = Feed::Transactions { address: params.into_inner().address };
+    event_stream_response(feed, &req, event_bus.into_inner(), block_service.into_inner(), transaction_service.into_inner())
+}
+
+fn event_stream_response(
+    feed: Feed,
+    req: &HttpRequest,
+    event_bus: Arc<dyn EventBus>,
+    block_service: Arc<dyn BlockService>,
+    transaction_service: Arc<dyn TransactionService>,
+) -> Result<HttpResponse, ApiError> {
+    let last_event_id = last_event_id(req)?;
+    let state = FeedState {
+        feed,
+        block_service,
+        transaction_service,
+        // Subscribe before replaying, so nothing committed in between is lost
+        events: event_bus.subscribe(),
+        replay_cursor: last_event_id,
+        replayed_up_to: last_event_id,
+        frames: VecDeque::new(),
+        keep_alive: interval(KEEP_ALIVE_INTERVAL),
+    };
+    let frames = stream::unfold(state, FeedState::next_frame);
+    Ok(HttpResponse::Ok().content_type("text/event-stream").insert_header((CACHE_CONTROL, "no-cache")).streaming(frames))
+}
+
+fn last_event_id(req: &HttpRequest) -> Result<Option<i32>, CommonError> {
+    let Some(header) = req.headers().get(LAST_EVENT_ID_HEADER) else {
+        return Ok(None);
+    };
+    let invalid_header = || CommonError {
+        message: format!("{} must be a block number", LAST_EVENT_ID_HEADER),
+        code: 3,
+    };
+    let block_number = header.to_str().map_err(|_| invalid_header())?.trim().parse().map_err(|_| invalid_header())?;
+    Ok(Some(block_number))
+}
+
+enum Feed {
+    Blocks,
+    Transactions { address: Option<Address> },
+}
+
+impl Feed {
+    fn accepts(&self, transaction: &Transaction) -> bool {
+        match self {
+            Feed::Blocks => false,
+            Feed::Transactions { address: None } => true,
+            Feed::Transactions { address: Some(address) } => transaction.from_address == *address || transaction.to_address == *address,
+        }
+    }
+}
+
+struct FeedState {
+    feed: Feed,
+    block_service: Arc<dyn BlockService>,
+    transaction_service: Arc<dyn TransactionService>,
+    events: Receiver<LedgerEvent>,
+    // Last block replayed from storage, replay is over once it is None
+    replay_cursor: Option<i32>,
+    // Live events of the blocks already replayed are skipped
+    replayed_up_to: Option<i32>,
+    frames: VecDeque<Bytes>,
+    keep_alive: Interval,
+}
+
+impl FeedState {
+    async fn next_frame(mut self) -> Option<(Result<Bytes, actix_web::Error>, Self)> {
+        loop {
+            if let Some(frame) = self.frames.pop_front() {
+                return Some((Ok(frame), self));
+            }
+
+            if let Some(cursor) = self.replay_cursor {
+                if let Err(e) = self.replay_page(cursor).await {
+                    warn!("Failed to replay the event stream after block {}: {}", cursor, e);
+                    return None;
+                }
+                continue;
+            }
+
+            tokio::select! {
+                event = self.events.recv() => match event {
+                    Ok(event) => self.push_event(event),
+                    // Ending the stream of a lagging client makes it reconnect and resume from its Last-Event-ID
+                    Err(_) => return None,
+                },
+                _ = self.keep_alive.tick() => self.frames.push_back(Bytes::from_static(b": keep-alive\n\n")),
+            }
+        }
+    }
+
+    // Only the blocks whose events were published are replayed, the events of a block still being produced arrive live
+    async fn replay_page(&mut self, cursor: i32) -> Result<(), CommonError> {
+        let blocks = self.block_service.list_after(cursor, REPLAY_PAGE_SIZE).await?;
+        if blocks.is_empty() {
+            self.replay_cursor = None;
+            return Ok(());
+        }
+
+        for block in blocks {
+            let block_number = block.block_number;
+            match self.feed {
+                Feed::Blocks => self.push_block(block),
+                Feed::Transactions { .. } => {
+                    for transaction in self.transaction_service.list_by_block(block_number).await? {
+                        self.push_transaction(transaction);
+                    }
+                }
+            }
+            self.replay_cursor = Some(block_number);
+            self.replayed_up_to = Some(block_number);
+        }
+        Ok(())
+    }
+
+    fn push_event(&mut self, event: LedgerEvent) {
+        let already_replayed = |block_number: i32| self.replayed_up_to.is_some_and(|replayed_up_to| block_number <= replayed_up_to);
+        match event {
+            LedgerEvent::NewBlock(block) if !already_replayed(block.block_number) => self.push_block(block),
+            LedgerEvent::TransactionStatus(transaction) if !transaction.block_number.is_some_and(already_replayed) => self.push_transaction(transaction),
+            _ => {}
+        }
+    }
+
+    fn push_block(&mut self, block: Block) {
+        if let Feed::Blocks = self.feed {
+            let block_number = block.block_number;
+            let block: BlockDTO = block.into();
+            self.frames.push_back(Bytes::from(sse_frame(block_number, "block", &block)));
+        }
+    }
+
+    fn push_transaction(&mut self, transaction: Transaction) {
+        if let (true, Some(block_number)) = (self.feed.accepts(&transaction), transaction.block_number) {
+            let transaction: TransactionDTO = transaction.into();
+            self.frames.push_back(Bytes::from(sse_frame(block_number, "transaction", &transaction)));
+        }
+    }
+}
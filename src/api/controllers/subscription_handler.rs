@@ -0,0 +1,134 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::api::dto::subscription::{subscription_topic, SubscriptionMessageDTO, SubscriptionRequestDTO};
+use crate::domain::models::ledger_event::{LedgerEvent, SubscriptionTopic};
+use crate::domain::services::event_bus::EventBus;
+use crate::domain::services::transaction::TransactionService;
+use actix_web::{rt, web, Error, HttpRequest, HttpResponse};
+use actix_ws::{Closed, Message, MessageStream, Session};
+use tokio::sync::broadcast::error::RecvError;
+
+#[utoipa::path(
+    get,
+    path = "/api/ws",
+    tag = "Subscriptions",
+    responses(
+        (status = 101, description = "Switched to the WebSocket protocol, the client then sends SubscriptionRequestDTO messages and receives SubscriptionMessageDTO messages"),
+        (status = 400, description = "Bad Request"),
+    )
+)]
+pub async fn subscription_handler(req: HttpRequest, body: web::Payload, event_bus: web::Data<dyn EventBus>, transaction_service: web::Data<dyn TransactionService>) -> Result<HttpResponse, Error> {
+    let (response, session, messages) = actix_ws::handle(&req, body)?;
+    rt::spawn(run_session(session, messages, event_bus.into_inner(), transaction_service.into_inner()));
+    Ok(response)
+}
+
+struct Subscriptions {
+    next_subscription_id: u64,
+    topics: BTreeMap<u64, SubscriptionTopic>,
+}
+
+async fn run_session(mut session: Session, mut messages: MessageStream, event_bus: Arc<dyn EventBus>, transaction_service: Arc<dyn TransactionService>) {
+    // Subscribe before reading any request, so nothing committed after a subscribe reply is missed
+    let mut events = event_bus.subscribe();
+    let mut subscriptions = Subscriptions {
+        next_subscription_id: 1,
+        topics: BTreeMap::new(),
+    };
+
+    loop {
+        let result = tokio::select! {
+            message = messages.recv() => match message {
+                Some(Ok(Message::Text(text))) => handle_request(&mut session, &mut subscriptions, transaction_service.as_ref(), &text).await,
+                Some(Ok(Message::Ping(bytes))) => session.pong(&bytes).await,
+                Some(Ok(Message::Close(reason))) => {
+                    let _ = session.close(reason).await;
+                    return;
+                }
+                Some(Ok(_)) => Ok(()),
+                Some(Err(_)) | None => break,
+            },
+            event = events.recv() => match event {
+                Ok(event) => push_event(&mut session, &subscriptions, event).await,
+                Err(RecvError::Lagged(skipped)) => {
+                    let message = format!("Subscriber lagged behind, {} events were dropped", skipped);
+                    send(&mut session, &SubscriptionMessageDTO::Error { message }).await
+                }
+                Err(RecvError::Closed) => break,
+            },
+        };
+        if result.is_err() {
+            // The client went away
+            return;
+        }
+    }
+    let _ = session.close(None).await;
+}
+
+async fn handle_request(session: &mut Session, subscriptions: &mut Subscriptions, transaction_service: &dyn TransactionService, text: &str) -> Result<(), Closed> {
+    let request: SubscriptionRequestDTO = match serde_json::from_str(text) {
+        Ok(request) => request,
+        Err(e) => {
+            let message = format!("Invalid subscription request: {}", e);
+            return send(session, &SubscriptionMessageDTO::Error { message }).await;
+        }
+    };
+
+    match request {
+        SubscriptionRequestDTO::Subscribe { topic, transaction_hash, address } => {
+            let topic = match subscription_topic(&topic, transaction_hash, address) {
+                Ok(topic) => topic,
+                Err(e) => return send(session, &SubscriptionMessageDTO::Error { message: e.message }).await,
+            };
+            let subscription_id = subscriptions.next_subscription_id;
+            subscriptions.next_subscription_id += 1;
+            subscriptions.topics.insert(subscription_id, topic.clone());
+            send(
+                session,
+                &SubscriptionMessageDTO::Subscribed {
+                    subscription_id,
+                    topic: topic.as_str().to_string(),
+                },
+            )
+            .await?;
+
+            // A transaction may already be mined when its status is subscribed to, push its current status right away
+            if let SubscriptionTopic::TransactionStatus { transaction_hash } = &topic {
+                if let Ok(transaction) = transaction_service.get(transaction_hash).await {
+                    let message = SubscriptionMessageDTO::Event {
+                        subscription_id,
+                        topic: topic.as_str().to_string(),
+                        data: Box::new(LedgerEvent::TransactionStatus(transaction).into()),
+                    };
+                    send(session, &message).await?;
+                }
+            }
+            Ok(())
+        }
+        SubscriptionRequestDTO::Unsubscribe { subscription_id } => match subscriptions.topics.remove(&subscription_id) {
+            Some(_) => send(session, &SubscriptionMessageDTO::Unsubscribed { subscription_id }).await,
+            None => {
+                let message = format!("Unknown subscription {}", subscription_id);
+                send(session, &SubscriptionMessageDTO::Error { message }).await
+            }
+        },
+    }
+}
+
+async fn push_event(session: &mut Session, subscriptions: &Subscriptions, event: LedgerEvent) -> Result<(), Closed> {
+    for (subscription_id, topic) in subscriptions.topics.iter().filter(|(_, topic)| topic.matches(&event)) {
+        let message = SubscriptionMessageDTO::Event {
+            subscription_id: *subscription_id,
+            topic: topic.as_str().to_string(),
+            data: Box::new(event.clone().into()),
+        };
+        send(session, &message).await?;
+    }
+    Ok(())
+}
+
+async fn send(session: &mut Session, message: &SubscriptionMessageDTO) -> Result<(), Closed> {
+    let text = serde_json::to_string(message).expect("Failed to serialize subscription message");
+    session.text(text).await
+}
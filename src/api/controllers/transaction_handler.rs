@@ -1,15 +1,23 @@
-use crate::api::dto::transaction::{CreateTransactionDTO, TransactionDTO};
-use crate::domain::error::ApiError;
+use crate::api::dto::transaction::{
+    CancelTransactionDTO, CreateTransactionDTO, TransactionBatchQueryParams, TransactionBatchResultDTO, TransactionDTO, TransactionSimulationDTO, IDEMPOTENCY_KEY_HEADER, MAX_IDEMPOTENCY_KEY_LENGTH,
+};
+use crate::domain::error::{ApiError, CommonError};
+use crate::domain::models::transaction::CreateTransaction;
 use crate::domain::repositories::repository::ResultPaging;
 use crate::domain::repositories::transaction::TransactionQueryParams;
+use crate::domain::services::chain::ChainService;
 use crate::domain::services::transaction::TransactionService;
-use actix_web::{web, HttpResponse, Result};
+use actix_web::{web, HttpRequest, Result};
+use serde_json::Value;
 
 #[utoipa::path(
     post,
     path = "/api/transactions",
     tag = "Transactions",
     request_body = CreateTransactionDTO,
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Retrying with the same key returns the transaction created by the first submission instead of a duplicate")
+    ),
     responses(
         (status = 200, description = "Transaction created successfully", body = CreateTransactionDTO),
         (status = 400, description = "Bad Request"),
@@ -18,11 +26,91 @@ use actix_web::{web, HttpResponse, Result};
         (status = 429, description = "Too Many Requests"),        
     )
 )]
-pub async fn create_transaction_handler(transaction_service: web::Data<dyn TransactionService>, post_data: web::Json<CreateTransactionDTO>) -> Result<web::Json<TransactionDTO>, ApiError> {
-    let transaction = transaction_service.create(post_data.into_inner().into()).await?;
+pub async fn create_transaction_handler(
+    req: HttpRequest,
+    transaction_service: web::Data<dyn TransactionService>,
+    post_data: web::Json<CreateTransactionDTO>,
+) -> Result<web::Json<TransactionDTO>, ApiError> {
+    let mut create_transaction: CreateTransaction = post_data.into_inner().into();
+    create_transaction.idempotency_key = idempotency_key(&req)?;
+    let transaction = transaction_service.create(create_transaction).await?;
     Ok(web::Json(transaction.into()))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/transactions/batch",
+    tag = "Transactions",
+    request_body = [CreateTransactionDTO],
+    params(
+        ("all_or_nothing" = Option<bool>, Query, description = "Rejects the whole batch when any of its transactions is invalid")
+    ),
+    responses(
+        (status = 200, description = "The valid transactions were submitted together, with the result of every item", body = TransactionBatchResultDTO),
+        (status = 400, description = "Bad Request"),
+        (status = 500, description = "Internal Server Error"),
+        (status = 503, description = "Service Unavailable"),
+        (status = 429, description = "Too Many Requests"),
+    )
+)]
+pub async fn create_transaction_batch_handler(
+    transaction_service: web::Data<dyn TransactionService>,
+    params: web::Query<TransactionBatchQueryParams>,
+    post_data: web::Json<Vec<Value>>,
+) -> Result<web::Json<TransactionBatchResultDTO>, ApiError> {
+    // A malformed transaction is reported in its position rather than failing the whole batch
+    let transactions: Vec<Result<CreateTransaction, CommonError>> = post_data
+        .into_inner()
+        .into_iter()
+        .map(|transaction| {
+            serde_json::from_value::<CreateTransactionDTO>(transaction)
+                .map(|transaction| transaction.into())
+                .map_err(|e| CommonError {
+                    message: format!("Invalid transaction: {}", e),
+                    code: 3,
+                })
+        })
+        .collect();
+    let results = transaction_service.create_batch(transactions, params.all_or_nothing.unwrap_or(false)).await?;
+    Ok(web::Json(results.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/transactions/simulate",
+    tag = "Transactions",
+    request_body = CreateTransactionDTO,
+    responses(
+        (status = 200, description = "Outcome the transaction would have in the next block, nothing is persisted", body = TransactionSimulationDTO),
+        (status = 400, description = "Bad Request"),
+        (status = 500, description = "Internal Server Error"),
+        (status = 503, description = "Service Unavailable"),
+        (status = 429, description = "Too Many Requests"),
+    )
+)]
+pub async fn simulate_transaction_handler(
+    transaction_service: web::Data<dyn TransactionService>,
+    chain_service: web::Data<dyn ChainService>,
+    post_data: web::Json<CreateTransactionDTO>,
+) -> Result<web::Json<TransactionSimulationDTO>, ApiError> {
+    let chain_info = chain_service.info().await?;
+    let simulation = transaction_service.simulate(chain_info.block_height + 1, post_data.into_inner().into()).await?;
+    Ok(web::Json(simulation.into()))
+}
+
+fn idempotency_key(req: &HttpRequest) -> Result<Option<String>, CommonError> {
+    let Some(header) = req.headers().get(IDEMPOTENCY_KEY_HEADER) else {
+        return Ok(None);
+    };
+    match header.to_str() {
+        Ok(key) if !key.is_empty() && key.len() <= MAX_IDEMPOTENCY_KEY_LENGTH => Ok(Some(key.to_string())),
+        _ => Err(CommonError {
+            message: format!("{} must be a visible ASCII string of 1 to {} characters", IDEMPOTENCY_KEY_HEADER, MAX_IDEMPOTENCY_KEY_LENGTH),
+            code: 3,
+        }),
+    }
+}
+
 #[utoipa::path(
     get,
     path = "/api/transactions",
@@ -61,21 +149,26 @@ pub async fn get_transaction_handler(transaction_service: web::Data<dyn Transact
 }
 
 #[utoipa::path(
-    delete,
-    path = "/api/transactions/{id}",
+    post,
+    path = "/api/transactions/{transaction_hash}/cancel",
     tag = "Transactions",
+    request_body = CancelTransactionDTO,
     params(
-        ("id", description = "Unique id of Transaction")
+        ("transaction_hash", description = "Hash of the pending Transaction")
     ),
     responses(
-        (status = 200, description = "Transaction found successfully"),
+        (status = 200, description = "Transaction cancelled successfully", body = TransactionDTO),
         (status = 400, description = "Bad Request"),
         (status = 500, description = "Internal Server Error"),
         (status = 503, description = "Service Unavailable"),
         (status = 429, description = "Too Many Requests"),
     )
 )]
-pub async fn delete_transaction_handler(transaction_service: web::Data<dyn TransactionService>, params: web::Path<i32>) -> Result<HttpResponse, ApiError> {
-    transaction_service.delete(params.into_inner()).await?;
-    Ok(HttpResponse::NoContent().finish())
+pub async fn cancel_transaction_handler(
+    transaction_service: web::Data<dyn TransactionService>,
+    params: web::Path<String>,
+    post_data: web::Json<CancelTransactionDTO>,
+) -> Result<web::Json<TransactionDTO>, ApiError> {
+    let transaction = transaction_service.cancel(&params.into_inner(), &post_data.from_address).await?;
+    Ok(web::Json(transaction.into()))
 }
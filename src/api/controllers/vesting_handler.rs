@@ -0,0 +1,33 @@
+use crate::api::dto::vesting::VestingScheduleDTO;
+use crate::domain::error::ApiError;
+use crate::domain::models::address::Address;
+use crate::domain::services::chain::ChainService;
+use crate::domain::services::vesting::VestingService;
+use actix_web::{web, Result};
+use chrono::Utc;
+
+#[utoipa::path(
+    get,
+    path = "/api/accounts/{address}/vesting",
+    tag = "Vesting",
+    params(
+        ("address", description = "Beneficiary address"),
+    ),
+    responses(
+        (status = 200, description = "Vesting schedules retrieved successfully, amounts as of the current block height and time", body = [VestingScheduleDTO]),
+        (status = 400, description = "Bad Request"),
+        (status = 500, description = "Internal Server Error"),
+        (status = 503, description = "Service Unavailable"),
+        (status = 429, description = "Too Many Requests"),
+    )
+)]
+pub async fn get_account_vesting_handler(
+    vesting_service: web::Data<dyn VestingService>,
+    chain_service: web::Data<dyn ChainService>,
+    param: web::Path<String>,
+) -> Result<web::Json<Vec<VestingScheduleDTO>>, ApiError> {
+    let address: Address = param.into_inner().parse()?;
+    let chain_info = chain_service.info().await?;
+    let statuses = vesting_service.status(&address, chain_info.block_height, Utc::now().timestamp()).await?;
+    Ok(web::Json(statuses.into_iter().map(|status| status.into()).collect()))
+}
@@ -1,5 +1,6 @@
 use crate::api::dto::wallet::WalletDTO;
 use crate::domain::error::ApiError;
+use crate::domain::models::address::Address;
 use crate::domain::repositories::repository::ResultPaging;
 use crate::domain::repositories::wallet::WalletQueryParams;
 use crate::domain::services::wallet::WalletService;
@@ -39,6 +40,9 @@ pub async fn list_wallet_handler(wallet_service: web::Data<dyn WalletService>, p
     )
 )]
 pub async fn get_wallet_handler(wallet_service: web::Data<dyn WalletService>, param: web::Path<(String, String)>) -> Result<web::Json<WalletDTO>, ApiError> {
-    let wallet = wallet_service.get(&param.0, &param.1).await?;
+    let (wallet_address, token_address) = param.into_inner();
+    let wallet_address: Address = wallet_address.parse()?;
+    let token_address: Address = token_address.parse()?;
+    let wallet = wallet_service.get(&wallet_address, &token_address).await?;
     Ok(web::Json(wallet.into()))
 }
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use crate::domain::models::address::Address;
 use crate::domain::models::block::Block;
 use crate::domain::repositories::repository::ResultPaging;
 
@@ -16,7 +17,8 @@ pub struct BlockDTO {
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct MineBlockDTO {
-    pub miner_address: String,
+    #[schema(value_type = String)]
+    pub miner_address: Address,
 }
 
 impl Into<BlockDTO> for Block {
@@ -26,7 +28,7 @@ impl Into<BlockDTO> for Block {
             block_hash: self.block_hash,
             parent_hash: self.parent_hash,
             timestamp: self.timestamp,
-            miner_address: self.miner_address,
+            miner_address: self.miner_address.into(),
             transaction_count: self.transaction_count,
         }
     }
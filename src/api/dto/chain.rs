@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::models::chain::ChainInfo;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ChainInfoDTO {
+    pub chain_id: String,
+    pub genesis_hash: String,
+    pub authorities: Vec<String>,
+    pub block_height: i32,
+    pub schema_version: Option<String>,
+}
+
+impl Into<ChainInfoDTO> for ChainInfo {
+    fn into(self) -> ChainInfoDTO {
+        ChainInfoDTO {
+            chain_id: self.chain_id,
+            genesis_hash: self.genesis_hash,
+            authorities: self.authorities.into_iter().map(String::from).collect(),
+            block_height: self.block_height,
+            schema_version: self.schema_version,
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::domain::models::fungible_token::FungibleToken;
+use crate::domain::models::fungible_token::{FungibleToken, OwnershipChange};
 use crate::domain::repositories::repository::ResultPaging;
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -14,19 +14,50 @@ pub struct FungibleTokenDTO {
     pub total_supply: i64,
     pub block_number: i32,
     pub transaction_hash: String,
+    pub minter_addresses: Vec<String>,
+    // Uncapped when null
+    pub max_supply: Option<i64>,
+    pub mintable: bool,
+    pub burnable: bool,
+    pub paused: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OwnershipChangeDTO {
+    pub previous_owner_address: String,
+    // The zero address when the ownership was renounced
+    pub new_owner_address: String,
+    pub block_number: i32,
+    pub transaction_hash: String,
 }
 
 impl Into<FungibleTokenDTO> for FungibleToken {
     fn into(self) -> FungibleTokenDTO {
         FungibleTokenDTO {
-            address: self.address,
+            address: self.address.into(),
             symbol: self.symbol,
             name: self.name,
-            owner_address: self.owner_address,
+            owner_address: self.owner_address.into(),
             decimals: self.decimals,
             total_supply: self.total_supply,
             block_number: self.block_number,
             transaction_hash: self.transaction_hash,
+            minter_addresses: self.minter_addresses.into_iter().map(String::from).collect(),
+            max_supply: self.max_supply,
+            mintable: self.mintable,
+            burnable: self.burnable,
+            paused: self.paused,
+        }
+    }
+}
+
+impl Into<OwnershipChangeDTO> for OwnershipChange {
+    fn into(self) -> OwnershipChangeDTO {
+        OwnershipChangeDTO {
+            previous_owner_address: self.previous_owner_address.into(),
+            new_owner_address: self.new_owner_address.into(),
+            block_number: self.block_number,
+            transaction_hash: self.transaction_hash,
         }
     }
 }
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::models::health::{HealthCheck, HealthReport, NodeInfo};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LivenessDTO {
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct HealthCheckDTO {
+    pub name: String,
+    pub status: String,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReadinessDTO {
+    pub status: String,
+    pub checks: Vec<HealthCheckDTO>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct NodeInfoDTO {
+    pub version: String,
+    pub git_commit: String,
+    pub chain_id: String,
+    pub block_height: i32,
+    pub mempool_size: i64,
+    pub schema_version: Option<String>,
+}
+
+pub const STATUS_UP: &str = "UP";
+pub const STATUS_DOWN: &str = "DOWN";
+
+fn status(healthy: bool) -> String {
+    if healthy { STATUS_UP } else { STATUS_DOWN }.to_string()
+}
+
+impl Into<HealthCheckDTO> for HealthCheck {
+    fn into(self) -> HealthCheckDTO {
+        HealthCheckDTO {
+            name: self.name,
+            status: status(self.healthy),
+            message: self.message,
+        }
+    }
+}
+
+impl Into<ReadinessDTO> for HealthReport {
+    fn into(self) -> ReadinessDTO {
+        ReadinessDTO {
+            status: status(self.healthy),
+            checks: self.checks.into_iter().map(|check| check.into()).collect(),
+        }
+    }
+}
+
+impl Into<NodeInfoDTO> for NodeInfo {
+    fn into(self) -> NodeInfoDTO {
+        NodeInfoDTO {
+            version: self.version,
+            git_commit: self.git_commit,
+            chain_id: self.chain_id,
+            block_height: self.block_height,
+            mempool_size: self.mempool_size,
+            schema_version: self.schema_version,
+        }
+    }
+}
@@ -1,5 +1,11 @@
 pub mod block;
+pub mod chain;
 pub mod fungible_token;
+pub mod health;
+pub mod rpc;
 pub mod service_context;
+pub mod stream;
+pub mod subscription;
 pub mod transaction;
+pub mod vesting;
 pub mod wallet;
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use utoipa::ToSchema;
+
+use crate::domain::error::CommonError;
+
+pub const JSON_RPC_VERSION: &str = "2.0";
+// Calls of a batch run one after the other, a larger batch is rejected as a whole
+pub const MAX_RPC_BATCH_SIZE: usize = 100;
+
+// Standard JSON-RPC 2.0 error codes
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+pub const INTERNAL_ERROR: i64 = -32603;
+// Implementation defined server error, the CommonError code is returned in its data
+pub const SERVER_ERROR: i64 = -32000;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct JsonRpcRequestDTO {
+    pub jsonrpc: String,
+    pub method: String,
+    pub params: Option<Value>,
+    pub id: Option<Value>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct JsonRpcResponseDTO {
+    pub jsonrpc: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<JsonRpcErrorDTO>,
+    pub id: Value,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct JsonRpcErrorDTO {
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl JsonRpcErrorDTO {
+    pub fn new(code: i64, message: impl Into<String>) -> Self {
+        JsonRpcErrorDTO {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+}
+
+impl JsonRpcResponseDTO {
+    pub fn success(id: Value, result: Value) -> Self {
+        JsonRpcResponseDTO {
+            jsonrpc: JSON_RPC_VERSION.to_string(),
+            result: Some(result),
+            error: None,
+            id,
+        }
+    }
+
+    pub fn failure(id: Value, error: JsonRpcErrorDTO) -> Self {
+        JsonRpcResponseDTO {
+            jsonrpc: JSON_RPC_VERSION.to_string(),
+            result: None,
+            error: Some(error),
+            id,
+        }
+    }
+}
+
+impl Into<JsonRpcErrorDTO> for CommonError {
+    fn into(self) -> JsonRpcErrorDTO {
+        JsonRpcErrorDTO {
+            code: SERVER_ERROR,
+            message: self.message,
+            data: Some(json!({ "code": self.code })),
+        }
+    }
+}
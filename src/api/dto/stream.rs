@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::models::address::Address;
+
+// Header sent back by EventSource clients when reconnecting, it holds the last block number they received
+pub const LAST_EVENT_ID_HEADER: &str = "Last-Event-ID";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransactionStreamQueryParams {
+    pub address: Option<Address>,
+}
+
+// Formats a Server-Sent Event frame, the JSON payload never spans several lines
+pub fn sse_frame<T: Serialize>(id: i32, event: &str, data: &T) -> String {
+    let data = serde_json::to_string(data).expect("Failed to serialize event data");
+    format!("id: {}\nevent: {}\ndata: {}\n\n", id, event, data)
+}
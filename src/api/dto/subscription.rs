@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::api::dto::block::BlockDTO;
+use crate::api::dto::transaction::TransactionDTO;
+use crate::api::dto::wallet::WalletDTO;
+use crate::domain::error::CommonError;
+use crate::domain::models::ledger_event::{LedgerEvent, SubscriptionTopic};
+
+// Messages sent by the client over the subscription socket
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum SubscriptionRequestDTO {
+    Subscribe {
+        topic: String,
+        transaction_hash: Option<String>,
+        address: Option<String>,
+    },
+    Unsubscribe {
+        subscription_id: u64,
+    },
+}
+
+// Messages pushed by the server over the subscription socket
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SubscriptionMessageDTO {
+    Subscribed { subscription_id: u64, topic: String },
+    Unsubscribed { subscription_id: u64 },
+    Event { subscription_id: u64, topic: String, data: Box<LedgerEventDTO> },
+    Error { message: String },
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum LedgerEventDTO {
+    Block(BlockDTO),
+    Transaction(TransactionDTO),
+    Wallet(WalletDTO),
+}
+
+impl Into<LedgerEventDTO> for LedgerEvent {
+    fn into(self) -> LedgerEventDTO {
+        match self {
+            LedgerEvent::NewBlock(block) => LedgerEventDTO::Block(block.into()),
+            LedgerEvent::PendingTransaction(transaction) | LedgerEvent::TransactionStatus(transaction) => LedgerEventDTO::Transaction(transaction.into()),
+            LedgerEvent::WalletChanged(wallet) => LedgerEventDTO::Wallet(wallet.into()),
+        }
+    }
+}
+
+// Resolves the topic of a subscribe request, checking that the filter it needs is present
+pub fn subscription_topic(topic: &str, transaction_hash: Option<String>, address: Option<String>) -> Result<SubscriptionTopic, CommonError> {
+    let missing_filter = |filter: &str| CommonError {
+        message: format!("Topic {} requires {}", topic, filter),
+        code: 3,
+    };
+
+    match topic {
+        "newBlocks" => Ok(SubscriptionTopic::NewBlocks),
+        "pendingTransactions" => Ok(SubscriptionTopic::PendingTransactions),
+        "transactionStatus" => Ok(SubscriptionTopic::TransactionStatus {
+            transaction_hash: transaction_hash.ok_or_else(|| missing_filter("transaction_hash"))?,
+        }),
+        "walletChanges" => Ok(SubscriptionTopic::WalletChanges {
+            address: address.ok_or_else(|| missing_filter("address"))?.parse()?,
+        }),
+        _ => Err(CommonError {
+            message: format!("Unknown topic {}", topic),
+            code: 3,
+        }),
+    }
+}
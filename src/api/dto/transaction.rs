@@ -1,20 +1,76 @@
 use chrono::prelude::*;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 
-use crate::domain::models::transaction::{CreateTransaction, Transaction};
-use crate::domain::models::transaction_type::TransactionType;
+use crate::api::dto::subscription::LedgerEventDTO;
+use crate::domain::error::CommonError;
+use crate::domain::models::address::Address;
+use crate::domain::models::ledger_event::LedgerEvent;
+use crate::domain::models::transaction::{BalanceDelta, CreateTransaction, Transaction, TransactionSimulation};
+use crate::domain::models::transaction_type::{AdministerFt, BurnFt, ClaimVestedFt, InitFt, MintFt, MultiTransferFt, TransactionType, TransferFt, VestingTransferFt};
 use crate::domain::repositories::repository::ResultPaging;
 use crate::utils::hex_utils::generate_transaction_hash;
 use utoipa::ToSchema;
 
+// Header making the submission of a transaction safe to retry, see IDEMPOTENCY_KEY_TTL_SECONDS
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+pub const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 255;
+
+// A transaction to submit, tagged by its transaction_type, each type with the metadata it expects as data
 #[derive(Deserialize, Serialize, ToSchema)]
-pub struct CreateTransactionDTO {
-    pub from_address: String,
-    pub to_address: String,
-    pub transaction_type: String,
+#[serde(tag = "transaction_type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CreateTransactionDTO {
+    InitFt(InitFtTransactionDTO),
+    MintFt(MintFtTransactionDTO),
+    BurnFt(BurnFtTransactionDTO),
+    TransferFt(TransferFtTransactionDTO),
+    MultiTransferFt(MultiTransferFtTransactionDTO),
+    TransferOwnershipFt(AdministerFtTransactionDTO),
+    RenounceOwnershipFt(AdministerFtTransactionDTO),
+    GrantMinterFt(AdministerFtTransactionDTO),
+    RevokeMinterFt(AdministerFtTransactionDTO),
+    PauseFt(AdministerFtTransactionDTO),
+    UnpauseFt(AdministerFtTransactionDTO),
+    FreezeAccount(AdministerFtTransactionDTO),
+    UnfreezeAccount(AdministerFtTransactionDTO),
+    VestingTransferFt(VestingTransferFtTransactionDTO),
+    ClaimVestedFt(ClaimVestedFtTransactionDTO),
+}
+
+// The fields every transaction type shares, with the metadata the domain reads for the type as data
+#[derive(Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[aliases(
+    InitFtTransactionDTO = TransactionRequestDTO<InitFt>,
+    MintFtTransactionDTO = TransactionRequestDTO<MintFt>,
+    BurnFtTransactionDTO = TransactionRequestDTO<BurnFt>,
+    TransferFtTransactionDTO = TransactionRequestDTO<TransferFt>,
+    MultiTransferFtTransactionDTO = TransactionRequestDTO<MultiTransferFt>,
+    AdministerFtTransactionDTO = TransactionRequestDTO<AdministerFt>,
+    VestingTransferFtTransactionDTO = TransactionRequestDTO<VestingTransferFt>,
+    ClaimVestedFtTransactionDTO = TransactionRequestDTO<ClaimVestedFt>
+)]
+pub struct TransactionRequestDTO<T> {
+    #[schema(value_type = String)]
+    pub from_address: Address,
+    // Left empty or out for the transactions without a recipient, such as BURN_FT
+    #[serde(default = "Address::zero", deserialize_with = "empty_as_zero_address")]
+    #[schema(value_type = String)]
+    pub to_address: Address,
     pub value: i64,
-    pub data: Option<Value>,
+    pub data: T,
+    // Priority offered for inclusion in a block, defaults to 0
+    pub fee: Option<i64>,
+    // Position among the transactions of the sender, defaults to its next nonce
+    pub nonce: Option<i64>,
+}
+
+fn empty_as_zero_address<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Address, D::Error> {
+    let address = String::deserialize(deserializer)?;
+    if address.is_empty() {
+        return Ok(Address::zero());
+    }
+    address.parse().map_err(|error: CommonError| serde::de::Error::custom(error.message))
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -30,48 +86,181 @@ pub struct TransactionDTO {
     pub data: Option<Value>,
     pub is_mined: Option<bool>,
     pub status: String,
+    pub fee: i64,
+    pub nonce: i64,
+    // Predicted address of the token an INIT_FT creates, the created one once mined
+    pub token_address: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct CancelTransactionDTO {
+    // Must be the sender of the transaction
+    #[schema(value_type = String)]
+    pub from_address: Address,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransactionBatchQueryParams {
+    // Rejects the whole batch when any of its transactions is invalid
+    pub all_or_nothing: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TransactionBatchResultDTO {
+    pub accepted: usize,
+    pub rejected: usize,
+    pub results: Vec<TransactionBatchItemDTO>,
+}
+
+// Either the hash of the submitted transaction or the reason it was rejected, in the order of the batch
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TransactionBatchItemDTO {
+    pub index: usize,
+    pub transaction_hash: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TransactionSimulationDTO {
+    pub status: String,
+    pub error: Option<String>,
+    pub balance_deltas: Vec<BalanceDeltaDTO>,
+    pub events: Vec<SimulatedEventDTO>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BalanceDeltaDTO {
+    pub wallet_address: String,
+    pub token_address: String,
+    pub balance_before: i64,
+    pub balance_after: i64,
+    pub delta: i64,
+}
+
+// An event the transaction would publish once mined, with the subscription topic it would be published on
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SimulatedEventDTO {
+    pub topic: String,
+    pub data: LedgerEventDTO,
 }
 
 impl Into<TransactionDTO> for Transaction {
     fn into(self) -> TransactionDTO {
+        let token_address = self.token_address().map(String::from);
         TransactionDTO {
             id: self.id,
             block_number: self.block_number,
             transaction_hash: self.transaction_hash,
-            from_address: self.from_address,
-            to_address: self.to_address,
+            from_address: self.from_address.into(),
+            to_address: self.to_address.into(),
             transaction_type: self.transaction_type.as_str().to_string(),
             value: self.value,
             timestamp: self.timestamp,
             data: self.data,
             is_mined: self.is_mined,
             status: self.status.to_string(),
+            fee: self.fee,
+            nonce: self.nonce,
+            token_address,
         }
     }
 }
 
-impl Into<CreateTransaction> for CreateTransactionDTO {
-    fn into(self) -> CreateTransaction {
+impl<T: Serialize> TransactionRequestDTO<T> {
+    fn into_create_transaction(self, transaction_type: TransactionType) -> CreateTransaction {
         CreateTransaction {
             transaction_hash: generate_transaction_hash(),
             from_address: self.from_address,
             to_address: self.to_address,
-            transaction_type: TransactionType::from_str(&self.transaction_type),
+            transaction_type,
             value: self.value,
             timestamp: Some(Utc::now().naive_utc()),
-            data: self.data,
+            // Stored as JSON, the domain reads it back into the same payload type
+            data: serde_json::to_value(self.data).ok(),
+            idempotency_key: None,
+            fee: self.fee.unwrap_or_default(),
+            nonce: self.nonce,
         }
     }
 }
 
-impl Into<CreateTransactionDTO> for CreateTransaction {
-    fn into(self) -> CreateTransactionDTO {
-        CreateTransactionDTO {
-            from_address: self.from_address,
-            to_address: self.to_address,
-            transaction_type: self.transaction_type.as_str().to_string(),
-            value: self.value,
-            data: self.data,
+impl Into<CreateTransaction> for CreateTransactionDTO {
+    fn into(self) -> CreateTransaction {
+        match self {
+            CreateTransactionDTO::InitFt(request) => request.into_create_transaction(TransactionType::InitFt),
+            CreateTransactionDTO::MintFt(request) => request.into_create_transaction(TransactionType::MintFt),
+            CreateTransactionDTO::BurnFt(request) => request.into_create_transaction(TransactionType::BurnFt),
+            CreateTransactionDTO::TransferFt(request) => request.into_create_transaction(TransactionType::TransferFt),
+            CreateTransactionDTO::MultiTransferFt(request) => request.into_create_transaction(TransactionType::MultiTransferFt),
+            CreateTransactionDTO::TransferOwnershipFt(request) => request.into_create_transaction(TransactionType::TransferOwnershipFt),
+            CreateTransactionDTO::RenounceOwnershipFt(request) => request.into_create_transaction(TransactionType::RenounceOwnershipFt),
+            CreateTransactionDTO::GrantMinterFt(request) => request.into_create_transaction(TransactionType::GrantMinterFt),
+            CreateTransactionDTO::RevokeMinterFt(request) => request.into_create_transaction(TransactionType::RevokeMinterFt),
+            CreateTransactionDTO::PauseFt(request) => request.into_create_transaction(TransactionType::PauseFt),
+            CreateTransactionDTO::UnpauseFt(request) => request.into_create_transaction(TransactionType::UnpauseFt),
+            CreateTransactionDTO::FreezeAccount(request) => request.into_create_transaction(TransactionType::FreezeAccount),
+            CreateTransactionDTO::UnfreezeAccount(request) => request.into_create_transaction(TransactionType::UnfreezeAccount),
+            CreateTransactionDTO::VestingTransferFt(request) => request.into_create_transaction(TransactionType::VestingTransferFt),
+            CreateTransactionDTO::ClaimVestedFt(request) => request.into_create_transaction(TransactionType::ClaimVestedFt),
+        }
+    }
+}
+
+impl Into<TransactionBatchResultDTO> for Vec<Result<Transaction, CommonError>> {
+    fn into(self) -> TransactionBatchResultDTO {
+        let results: Vec<TransactionBatchItemDTO> = self
+            .into_iter()
+            .enumerate()
+            .map(|(index, result)| match result {
+                Ok(transaction) => TransactionBatchItemDTO {
+                    index,
+                    transaction_hash: Some(transaction.transaction_hash),
+                    error: None,
+                },
+                Err(error) => TransactionBatchItemDTO {
+                    index,
+                    transaction_hash: None,
+                    error: Some(error.message),
+                },
+            })
+            .collect();
+        let accepted = results.iter().filter(|result| result.transaction_hash.is_some()).count();
+        TransactionBatchResultDTO {
+            accepted,
+            rejected: results.len() - accepted,
+            results,
+        }
+    }
+}
+
+impl Into<TransactionSimulationDTO> for TransactionSimulation {
+    fn into(self) -> TransactionSimulationDTO {
+        TransactionSimulationDTO {
+            status: self.status.to_string(),
+            error: self.error,
+            balance_deltas: self.balance_deltas.into_iter().map(|delta| delta.into()).collect(),
+            events: self.events.into_iter().map(|event| event.into()).collect(),
+        }
+    }
+}
+
+impl Into<BalanceDeltaDTO> for BalanceDelta {
+    fn into(self) -> BalanceDeltaDTO {
+        BalanceDeltaDTO {
+            wallet_address: self.address.into(),
+            token_address: self.token_address.into(),
+            balance_before: self.balance_before,
+            balance_after: self.balance_after,
+            delta: self.balance_after - self.balance_before,
+        }
+    }
+}
+
+impl Into<SimulatedEventDTO> for LedgerEvent {
+    fn into(self) -> SimulatedEventDTO {
+        SimulatedEventDTO {
+            topic: self.topic().to_string(),
+            data: self.into(),
         }
     }
 }
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::models::vesting::VestingStatus;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct VestingScheduleDTO {
+    pub id: i32,
+    pub token_address: String,
+    pub grantor_address: String,
+    pub beneficiary_address: String,
+    pub unit: String,
+    pub start: i64,
+    pub cliff: i64,
+    pub duration: i64,
+    pub total_amount: i64,
+    pub vested_amount: i64,
+    pub claimed_amount: i64,
+    // Not vested yet
+    pub locked_amount: i64,
+    // Vested but not claimed yet
+    pub claimable_amount: i64,
+    pub created_at_block_number: i32,
+    pub created_by_transaction_hash: String,
+}
+
+impl Into<VestingScheduleDTO> for VestingStatus {
+    fn into(self) -> VestingScheduleDTO {
+        let schedule = self.schedule;
+        VestingScheduleDTO {
+            id: schedule.id,
+            token_address: schedule.token_address.into(),
+            grantor_address: schedule.grantor_address.into(),
+            beneficiary_address: schedule.beneficiary_address.into(),
+            unit: schedule.unit.to_string(),
+            start: schedule.start,
+            cliff: schedule.cliff,
+            duration: schedule.duration,
+            total_amount: schedule.total_amount,
+            vested_amount: self.vested_amount,
+            claimed_amount: schedule.claimed_amount,
+            locked_amount: schedule.total_amount - self.vested_amount,
+            claimable_amount: self.vested_amount - schedule.claimed_amount,
+            created_at_block_number: schedule.block_number,
+            created_by_transaction_hash: schedule.transaction_hash,
+        }
+    }
+}
@@ -10,16 +10,18 @@ pub struct WalletDTO {
     pub balance: i64,
     pub updated_at_block_number: i32,
     pub updated_by_transaction_hash: String,
+    pub frozen: bool,
 }
 
 impl Into<WalletDTO> for Wallet {
     fn into(self) -> WalletDTO {
         WalletDTO {
-            wallet_address: self.address,
-            token_address: self.token_address,
+            wallet_address: self.address.into(),
+            token_address: self.token_address.into(),
             balance: self.balance,
             updated_at_block_number: self.block_number,
             updated_by_transaction_hash: self.transaction_hash,
+            frozen: self.frozen,
         }
     }
 }
@@ -0,0 +1,293 @@
+use std::sync::Arc;
+
+use async_graphql::{ComplexObject, Context, EmptyMutation, EmptySubscription, ErrorExtensions, Json, Object, Result, Schema, SimpleObject};
+use serde_json::Value;
+
+use crate::container::Container;
+use crate::domain::error::CommonError;
+use crate::domain::models::address::Address;
+use crate::domain::models::block::Block;
+use crate::domain::models::fungible_token::FungibleToken;
+use crate::domain::models::transaction::Transaction;
+use crate::domain::models::wallet::Wallet;
+use crate::domain::repositories::block::BlockQueryParams;
+use crate::domain::repositories::fungible_token::FungibleTokenQueryParams;
+use crate::domain::repositories::transaction::TransactionQueryParams;
+use crate::domain::repositories::wallet::WalletQueryParams;
+use crate::domain::services::block::BlockService;
+use crate::domain::services::fungible_token::FungibleTokenService;
+use crate::domain::services::transaction::TransactionService;
+use crate::domain::services::wallet::WalletService;
+
+pub type LedgerSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+// Bounds the nesting of explorer queries such as block -> transactions -> token -> holders -> token...
+const MAX_QUERY_DEPTH: usize = 8;
+
+pub fn build_schema(container: &Container) -> LedgerSchema {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .data(container.block_service.clone())
+        .data(container.transaction_service.clone())
+        .data(container.wallet_service.clone())
+        .data(container.fungible_token_service.clone())
+        .limit_depth(MAX_QUERY_DEPTH)
+        .finish()
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "Block", complex)]
+pub struct BlockObject {
+    pub block_number: i32,
+    pub block_hash: String,
+    pub parent_hash: String,
+    pub timestamp: Option<chrono::NaiveDateTime>,
+    pub miner_address: String,
+    pub transaction_count: i32,
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "Transaction", complex)]
+pub struct TransactionObject {
+    pub id: i32,
+    pub block_number: Option<i32>,
+    pub transaction_hash: String,
+    pub from_address: String,
+    pub to_address: String,
+    pub transaction_type: String,
+    pub value: i64,
+    pub timestamp: Option<chrono::NaiveDateTime>,
+    pub data: Option<Json<Value>>,
+    pub is_mined: Option<bool>,
+    pub status: String,
+    pub fee: i64,
+    pub nonce: i64,
+    // Predicted address of the token an INIT_FT creates, the created one once mined
+    pub token_address: Option<String>,
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "FungibleToken", complex)]
+pub struct FungibleTokenObject {
+    pub address: String,
+    pub symbol: String,
+    pub name: String,
+    pub owner_address: String,
+    pub decimals: i32,
+    pub total_supply: i64,
+    pub block_number: i32,
+    pub transaction_hash: String,
+    pub minter_addresses: Vec<String>,
+    pub max_supply: Option<i64>,
+    pub mintable: bool,
+    pub burnable: bool,
+    pub paused: bool,
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "Wallet", complex)]
+pub struct WalletObject {
+    pub wallet_address: String,
+    pub token_address: String,
+    pub balance: i64,
+    pub updated_at_block_number: i32,
+    pub updated_by_transaction_hash: String,
+    pub frozen: bool,
+}
+
+impl Into<BlockObject> for Block {
+    fn into(self) -> BlockObject {
+        BlockObject {
+            block_number: self.block_number,
+            block_hash: self.block_hash,
+            parent_hash: self.parent_hash,
+            timestamp: self.timestamp,
+            miner_address: self.miner_address.into(),
+            transaction_count: self.transaction_count,
+        }
+    }
+}
+
+impl Into<TransactionObject> for Transaction {
+    fn into(self) -> TransactionObject {
+        let token_address = self.token_address().map(String::from);
+        TransactionObject {
+            id: self.id,
+            block_number: self.block_number,
+            transaction_hash: self.transaction_hash,
+            from_address: self.from_address.into(),
+            to_address: self.to_address.into(),
+            transaction_type: self.transaction_type.as_str().to_string(),
+            value: self.value,
+            timestamp: self.timestamp,
+            data: self.data.map(Json),
+            is_mined: self.is_mined,
+            status: self.status.to_string(),
+            fee: self.fee,
+            nonce: self.nonce,
+            token_address,
+        }
+    }
+}
+
+impl Into<FungibleTokenObject> for FungibleToken {
+    fn into(self) -> FungibleTokenObject {
+        FungibleTokenObject {
+            address: self.address.into(),
+            symbol: self.symbol,
+            name: self.name,
+            owner_address: self.owner_address.into(),
+            decimals: self.decimals,
+            total_supply: self.total_supply,
+            block_number: self.block_number,
+            transaction_hash: self.transaction_hash,
+            minter_addresses: self.minter_addresses.into_iter().map(String::from).collect(),
+            max_supply: self.max_supply,
+            mintable: self.mintable,
+            burnable: self.burnable,
+            paused: self.paused,
+        }
+    }
+}
+
+impl Into<WalletObject> for Wallet {
+    fn into(self) -> WalletObject {
+        WalletObject {
+            wallet_address: self.address.into(),
+            token_address: self.token_address.into(),
+            balance: self.balance,
+            updated_at_block_number: self.block_number,
+            updated_by_transaction_hash: self.transaction_hash,
+            frozen: self.frozen,
+        }
+    }
+}
+
+#[ComplexObject]
+impl BlockObject {
+    async fn transactions(&self, ctx: &Context<'_>) -> Result<Vec<TransactionObject>> {
+        let transaction_service = ctx.data_unchecked::<Arc<dyn TransactionService>>();
+        let transactions = transaction_service.list_by_block(self.block_number).await.map_err(graphql_error)?;
+        Ok(transactions.into_iter().map(|transaction| transaction.into()).collect())
+    }
+}
+
+#[ComplexObject]
+impl TransactionObject {
+    async fn block(&self, ctx: &Context<'_>) -> Result<Option<BlockObject>> {
+        let Some(block_number) = self.block_number else {
+            return Ok(None);
+        };
+        let block_service = ctx.data_unchecked::<Arc<dyn BlockService>>();
+        let block = block_service.get(block_number).await.map_err(graphql_error)?;
+        Ok(Some(block.into()))
+    }
+
+    // The token the transaction operates on, or the one an INIT_FT creates once it is mined
+    async fn token(&self, ctx: &Context<'_>) -> Result<Option<FungibleTokenObject>> {
+        let token_address = match &self.token_address {
+            Some(created_token_address) if self.is_mined == Some(true) => created_token_address.as_str(),
+            Some(_) => return Ok(None),
+            None => match self.data.as_ref().and_then(|data| data.get("token_address")).and_then(Value::as_str) {
+                Some(token_address) => token_address,
+                None => return Ok(None),
+            },
+        };
+        let token_address: Address = token_address.parse().map_err(graphql_error)?;
+        let fungible_token_service = ctx.data_unchecked::<Arc<dyn FungibleTokenService>>();
+        let fungible_token = fungible_token_service.get(&token_address).await.map_err(graphql_error)?;
+        Ok(Some(fungible_token.into()))
+    }
+}
+
+#[ComplexObject]
+impl FungibleTokenObject {
+    async fn holders(&self, ctx: &Context<'_>, limit: Option<i64>, offset: Option<i64>) -> Result<Vec<WalletObject>> {
+        let wallet_service = ctx.data_unchecked::<Arc<dyn WalletService>>();
+        let params = WalletQueryParams {
+            limit,
+            offset,
+            address: None,
+            token_address: Some(self.address.parse().map_err(graphql_error)?),
+        };
+        let wallets = wallet_service.list(params).await.map_err(graphql_error)?;
+        Ok(wallets.items.into_iter().map(|wallet| wallet.into()).collect())
+    }
+}
+
+#[ComplexObject]
+impl WalletObject {
+    async fn token(&self, ctx: &Context<'_>) -> Result<FungibleTokenObject> {
+        let fungible_token_service = ctx.data_unchecked::<Arc<dyn FungibleTokenService>>();
+        let fungible_token = fungible_token_service.get(&self.token_address).await.map_err(graphql_error)?;
+        Ok(fungible_token.into())
+    }
+}
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    async fn block(&self, ctx: &Context<'_>, block_number: i32) -> Result<BlockObject> {
+        let block_service = ctx.data_unchecked::<Arc<dyn BlockService>>();
+        let block = block_service.get(block_number).await.map_err(graphql_error)?;
+        Ok(block.into())
+    }
+
+    // Latest block first
+    async fn blocks(&self, ctx: &Context<'_>, limit: Option<i64>, offset: Option<i64>) -> Result<Vec<BlockObject>> {
+        let block_service = ctx.data_unchecked::<Arc<dyn BlockService>>();
+        let blocks = block_service.list(BlockQueryParams { limit, offset }).await.map_err(graphql_error)?;
+        Ok(blocks.items.into_iter().map(|block| block.into()).collect())
+    }
+
+    async fn transaction(&self, ctx: &Context<'_>, transaction_hash: String) -> Result<TransactionObject> {
+        let transaction_service = ctx.data_unchecked::<Arc<dyn TransactionService>>();
+        let transaction = transaction_service.get(&transaction_hash).await.map_err(graphql_error)?;
+        Ok(transaction.into())
+    }
+
+    async fn transactions(&self, ctx: &Context<'_>, limit: Option<i64>, offset: Option<i64>, is_mined: Option<bool>) -> Result<Vec<TransactionObject>> {
+        let transaction_service = ctx.data_unchecked::<Arc<dyn TransactionService>>();
+        let params = TransactionQueryParams { limit, offset, is_mined };
+        let transactions = transaction_service.list(params).await.map_err(graphql_error)?;
+        Ok(transactions.items.into_iter().map(|transaction| transaction.into()).collect())
+    }
+
+    async fn fungible_token(&self, ctx: &Context<'_>, address: String) -> Result<FungibleTokenObject> {
+        let address: Address = address.parse().map_err(graphql_error)?;
+        let fungible_token_service = ctx.data_unchecked::<Arc<dyn FungibleTokenService>>();
+        let fungible_token = fungible_token_service.get(&address).await.map_err(graphql_error)?;
+        Ok(fungible_token.into())
+    }
+
+    async fn fungible_tokens(&self, ctx: &Context<'_>, limit: Option<i64>, offset: Option<i64>, name_prefix: Option<String>) -> Result<Vec<FungibleTokenObject>> {
+        let fungible_token_service = ctx.data_unchecked::<Arc<dyn FungibleTokenService>>();
+        let fungible_tokens = fungible_token_service.list(FungibleTokenQueryParams { limit, offset, name_prefix }).await.map_err(graphql_error)?;
+        Ok(fungible_tokens.items.into_iter().map(|fungible_token| fungible_token.into()).collect())
+    }
+
+    async fn wallet(&self, ctx: &Context<'_>, wallet_address: String, token_address: String) -> Result<WalletObject> {
+        let wallet_address: Address = wallet_address.parse().map_err(graphql_error)?;
+        let token_address: Address = token_address.parse().map_err(graphql_error)?;
+        let wallet_service = ctx.data_unchecked::<Arc<dyn WalletService>>();
+        let wallet = wallet_service.get(&wallet_address, &token_address).await.map_err(graphql_error)?;
+        Ok(wallet.into())
+    }
+
+    async fn wallets(&self, ctx: &Context<'_>, wallet_address: Option<String>, token_address: Option<String>, limit: Option<i64>, offset: Option<i64>) -> Result<Vec<WalletObject>> {
+        let wallet_service = ctx.data_unchecked::<Arc<dyn WalletService>>();
+        let params = WalletQueryParams {
+            limit,
+            offset,
+            address: wallet_address.map(|address| address.parse()).transpose().map_err(graphql_error)?,
+            token_address: token_address.map(|address| address.parse()).transpose().map_err(graphql_error)?,
+        };
+        let wallets = wallet_service.list(params).await.map_err(graphql_error)?;
+        Ok(wallets.items.into_iter().map(|wallet| wallet.into()).collect())
+    }
+}
+
+fn graphql_error(error: CommonError) -> async_graphql::Error {
+    let code = error.code;
+    async_graphql::Error::new(error.message).extend_with(|_, extensions| extensions.set("code", code))
+}
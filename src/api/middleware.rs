@@ -1,13 +1,22 @@
 use std::future::{ready, Ready};
+use std::time::Instant;
 
+use crate::domain::services::metrics::MetricsService;
 use crate::domain::services::service_context::ServiceContextService;
 use actix_web::{
     body::EitherBody,
     dev::{self, Service, ServiceRequest, ServiceResponse, Transform},
-    web, Error, HttpResponse,
+    http::header::{HeaderName, HeaderValue},
+    web, Error, HttpMessage, HttpResponse,
 };
 use futures_util::future::LocalBoxFuture;
 use log::info;
+use tracing_actix_web::RequestId;
+
+// Admin endpoints and the probes keep answering while maintenance mode is active, the entries ending with / exempt every path below them
+const MAINTENANCE_EXEMPT_PATHS: [&str; 4] = ["/admin/", "/health/", "/info", "/metrics"];
+const UNMATCHED_ROUTE: &str = "unmatched";
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
 
 pub struct ServiceContextMaintenanceCheck;
 
@@ -46,8 +55,7 @@ where
     fn call(&self, request: ServiceRequest) -> Self::Future {
         let service_context_service = request.app_data::<web::Data<dyn ServiceContextService>>().unwrap();
 
-        // Ignore requests coming from admin endpoints even when maintenance mode is active
-        if !request.uri().path().starts_with("/admin/") && service_context_service.is_maintenance_active() {
+        if !is_maintenance_exempt(request.uri().path()) && service_context_service.is_maintenance_active() {
             info!("Service is in maintenance mode");
             let (request, _pl) = request.into_parts();
             let response = HttpResponse::ServiceUnavailable().finish().map_into_right_body();
@@ -61,3 +69,109 @@ where
         })
     }
 }
+
+fn is_maintenance_exempt(request_path: &str) -> bool {
+    MAINTENANCE_EXEMPT_PATHS
+        .iter()
+        .any(|path| if path.ends_with('/') { request_path.starts_with(path) } else { request_path == *path })
+}
+
+// Records the count and latency of every request, labelled with the matched route pattern to keep the cardinality bounded
+pub struct HttpMetrics;
+
+impl<S, B> Transform<S, ServiceRequest> for HttpMetrics
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = HttpMetricsMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(HttpMetricsMiddleware { service }))
+    }
+}
+pub struct HttpMetricsMiddleware<S> {
+    service: S,
+}
+
+impl<S, B> Service<ServiceRequest> for HttpMetricsMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    dev::forward_ready!(service);
+
+    fn call(&self, request: ServiceRequest) -> Self::Future {
+        let metrics_service = request.app_data::<web::Data<dyn MetricsService>>().unwrap().clone();
+        let method = request.method().to_string();
+        let started_at = Instant::now();
+
+        let res = self.service.call(request);
+        Box::pin(async move {
+            let response = res.await?;
+            let route = response.request().match_pattern().unwrap_or_else(|| UNMATCHED_ROUTE.to_string());
+            metrics_service.observe_http_request(&method, &route, response.status().as_u16(), started_at.elapsed().as_secs_f64());
+            Ok(response)
+        })
+    }
+}
+
+// Returns the request ID generated by TracingLogger so clients can correlate a response with the logs
+pub struct RequestIdHeader;
+
+impl<S, B> Transform<S, ServiceRequest> for RequestIdHeader
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = RequestIdHeaderMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequestIdHeaderMiddleware { service }))
+    }
+}
+pub struct RequestIdHeaderMiddleware<S> {
+    service: S,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestIdHeaderMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    dev::forward_ready!(service);
+
+    fn call(&self, request: ServiceRequest) -> Self::Future {
+        let request_id = request.extensions().get::<RequestId>().copied();
+
+        let res = self.service.call(request);
+        Box::pin(async move {
+            let mut response = res.await?;
+            if let Some(request_id) = request_id {
+                let header_value = HeaderValue::from_str(&request_id.to_string()).unwrap();
+                response.headers_mut().insert(HeaderName::from_static(REQUEST_ID_HEADER), header_value);
+            }
+            Ok(response)
+        })
+    }
+}
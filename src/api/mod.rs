@@ -1,3 +1,4 @@
 pub mod controllers;
 pub mod dto;
+pub mod graphql;
 pub mod middleware;
@@ -1,70 +1,307 @@
+use crate::domain::constants::{
+    BLOCK_PRODUCER_MAX_IDLE_SECONDS, DEFAULT_IDEMPOTENCY_KEY_TTL_SECONDS, DEFAULT_MEMPOOL_MAX_PENDING_PER_SENDER, DEFAULT_MEMPOOL_TRANSACTION_TTL_SECONDS, IDEMPOTENCY_KEY_TTL_SECONDS,
+    MEMPOOL_MAX_PENDING_PER_SENDER, MEMPOOL_TRANSACTION_TTL_SECONDS, RESERVED_TOKEN_SYMBOLS, STORAGE_BACKEND,
+};
+use crate::domain::models::address::Address;
+use crate::domain::models::genesis::Genesis;
 use crate::domain::repositories::block::BlockRepository;
 use crate::domain::repositories::fungible_token::FungibleTokenRepository;
+use crate::domain::repositories::ledger::LedgerRepository;
 use crate::domain::repositories::transaction::TransactionRepository;
+use crate::domain::repositories::vesting::VestingRepository;
 use crate::domain::repositories::wallet::WalletRepository;
 use crate::domain::services::block::BlockService;
+use crate::domain::services::chain::ChainService;
+use crate::domain::services::event_bus::EventBus;
 use crate::domain::services::fungible_token::FungibleTokenService;
+use crate::domain::services::health::HealthService;
+use crate::domain::services::mempool::MempoolService;
+use crate::domain::services::metrics::MetricsService;
+use crate::domain::services::schema::SchemaService;
 use crate::domain::services::service_context::ServiceContextService;
 use crate::domain::services::transaction::TransactionService;
+use crate::domain::services::vesting::VestingService;
 use crate::domain::services::wallet::WalletService;
-use crate::infrastructure::databases::postgresql::db_pool;
+use crate::infrastructure::databases::connection::DBConn;
+use crate::infrastructure::databases::{postgresql, sqlite};
+use crate::infrastructure::genesis::load_genesis;
 use crate::infrastructure::repositories::block::BlockDieselRepository;
 use crate::infrastructure::repositories::fungible_token::FungibleTokenDieselRepository;
+use crate::infrastructure::repositories::in_memory::block::BlockInMemoryRepository;
+use crate::infrastructure::repositories::in_memory::fungible_token::FungibleTokenInMemoryRepository;
+use crate::infrastructure::repositories::in_memory::ledger::LedgerInMemoryRepository;
+use crate::infrastructure::repositories::in_memory::transaction::TransactionInMemoryRepository;
+use crate::infrastructure::repositories::in_memory::vesting::VestingInMemoryRepository;
+use crate::infrastructure::repositories::in_memory::wallet::WalletInMemoryRepository;
+use crate::infrastructure::repositories::ledger::LedgerDieselRepository;
 use crate::infrastructure::repositories::transaction::TransactionDieselRepository;
+use crate::infrastructure::repositories::vesting::VestingDieselRepository;
 use crate::infrastructure::repositories::wallet::WalletDieselRepository;
+use crate::infrastructure::services::in_memory::schema::SchemaInMemoryServiceImpl;
+use crate::infrastructure::services::in_memory::service_context::ServiceContextInMemoryServiceImpl;
+use crate::infrastructure::services::metrics::PrometheusMetricsServiceImpl;
+use crate::infrastructure::services::schema::SchemaServiceImpl;
 use crate::infrastructure::services::service_context::ServiceContextServiceImpl;
 use crate::services::block::BlockServiceImpl;
+use crate::services::chain::ChainServiceImpl;
+use crate::services::event_bus::BroadcastEventBus;
 use crate::services::fungible_token::FungibleTokenServiceImpl;
+use crate::services::health::HealthServiceImpl;
+use crate::services::ledger::LedgerServiceImpl;
+use crate::services::mempool::MempoolServiceImpl;
 use crate::services::transaction::TransactionServiceImpl;
+use crate::services::vesting::VestingServiceImpl;
 use crate::services::wallet::WalletServiceImpl;
+use chrono::Duration;
+use dotenv::dotenv;
+use std::collections::HashMap;
+use std::env;
 use std::sync::Arc;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StorageBackend {
+    Postgresql,
+    Sqlite,
+    InMemory,
+}
+
+impl StorageBackend {
+    // Reads STORAGE_BACKEND, defaulting to PostgreSQL
+    pub fn from_env() -> Self {
+        dotenv().ok();
+        match env::var(STORAGE_BACKEND).unwrap_or_default().to_lowercase().as_str() {
+            "" | "postgres" | "postgresql" => StorageBackend::Postgresql,
+            "sqlite" => StorageBackend::Sqlite,
+            "memory" | "in_memory" => StorageBackend::InMemory,
+            other => panic!("Unsupported {}: {}", STORAGE_BACKEND, other),
+        }
+    }
+}
+
+// Readiness fails when no block was produced for BLOCK_PRODUCER_MAX_IDLE_SECONDS, blocks are mined on demand so it is unset by default
+fn block_producer_max_idle() -> Option<Duration> {
+    dotenv().ok();
+    env::var(BLOCK_PRODUCER_MAX_IDLE_SECONDS).ok().map(|seconds| {
+        let seconds: i64 = seconds.parse().unwrap_or_else(|_| panic!("Failed to parse {} as i64", BLOCK_PRODUCER_MAX_IDLE_SECONDS));
+        Duration::seconds(seconds)
+    })
+}
+
+// Retried submissions carrying the same Idempotency-Key return the original transaction for IDEMPOTENCY_KEY_TTL_SECONDS
+fn idempotency_key_ttl() -> Duration {
+    dotenv().ok();
+    let seconds = env::var(IDEMPOTENCY_KEY_TTL_SECONDS)
+        .map(|seconds| seconds.parse().unwrap_or_else(|_| panic!("Failed to parse {} as i64", IDEMPOTENCY_KEY_TTL_SECONDS)))
+        .unwrap_or(DEFAULT_IDEMPOTENCY_KEY_TTL_SECONDS);
+    Duration::seconds(seconds)
+}
+
+// Pending transactions not mined within MEMPOOL_TRANSACTION_TTL_SECONDS of their submission expire
+fn mempool_transaction_ttl() -> Duration {
+    dotenv().ok();
+    let seconds = env::var(MEMPOOL_TRANSACTION_TTL_SECONDS)
+        .map(|seconds| seconds.parse().unwrap_or_else(|_| panic!("Failed to parse {} as i64", MEMPOOL_TRANSACTION_TTL_SECONDS)))
+        .unwrap_or(DEFAULT_MEMPOOL_TRANSACTION_TTL_SECONDS);
+    Duration::seconds(seconds)
+}
+
+fn mempool_max_pending_per_sender() -> i64 {
+    dotenv().ok();
+    env::var(MEMPOOL_MAX_PENDING_PER_SENDER)
+        .map(|count| count.parse().unwrap_or_else(|_| panic!("Failed to parse {} as i64", MEMPOOL_MAX_PENDING_PER_SENDER)))
+        .unwrap_or(DEFAULT_MEMPOOL_MAX_PENDING_PER_SENDER)
+}
+
+// RESERVED_TOKEN_SYMBOLS lists symbols nobody may issue, or only the given issuer with SYMBOL:ADDRESS, e.g. USDC:<issuer address>,ETH
+fn reserved_token_symbols() -> HashMap<String, Option<Address>> {
+    dotenv().ok();
+    env::var(RESERVED_TOKEN_SYMBOLS)
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| match entry.split_once(':') {
+            Some((symbol, issuer_address)) => {
+                let issuer_address = issuer_address
+                    .trim()
+                    .parse()
+                    .unwrap_or_else(|_| panic!("Failed to parse {} issuer {} as an address", RESERVED_TOKEN_SYMBOLS, issuer_address));
+                (symbol.trim().to_uppercase(), Some(issuer_address))
+            }
+            None => (entry.to_uppercase(), None),
+        })
+        .collect()
+}
+
+struct Storage {
+    db_pool: Option<Arc<DBConn>>,
+    schema_service: Arc<dyn SchemaService>,
+    service_context_service: Arc<dyn ServiceContextService>,
+    block_repository: Arc<dyn BlockRepository>,
+    transaction_repository: Arc<dyn TransactionRepository>,
+    wallet_repository: Arc<dyn WalletRepository>,
+    fungible_token_repository: Arc<dyn FungibleTokenRepository>,
+    vesting_repository: Arc<dyn VestingRepository>,
+    ledger_repository: Arc<dyn LedgerRepository>,
+}
+
+impl Storage {
+    fn diesel(db_pool: DBConn) -> Self {
+        let db_pool = Arc::new(db_pool); // Create the database pool only once
+        Storage {
+            db_pool: Some(Arc::clone(&db_pool)),
+            schema_service: Arc::new(SchemaServiceImpl::new(Arc::clone(&db_pool))),
+            service_context_service: Arc::new(ServiceContextServiceImpl::new(Arc::clone(&db_pool))),
+            block_repository: Arc::new(BlockDieselRepository::new(Arc::clone(&db_pool))),
+            transaction_repository: Arc::new(TransactionDieselRepository::new(Arc::clone(&db_pool))),
+            wallet_repository: Arc::new(WalletDieselRepository::new(Arc::clone(&db_pool))),
+            fungible_token_repository: Arc::new(FungibleTokenDieselRepository::new(Arc::clone(&db_pool))),
+            vesting_repository: Arc::new(VestingDieselRepository::new(Arc::clone(&db_pool))),
+            ledger_repository: Arc::new(LedgerDieselRepository::new(Arc::clone(&db_pool))),
+        }
+    }
+
+    fn in_memory() -> Self {
+        // The ledger repository writes through the same collections as the other repositories
+        let block_repository = Arc::new(BlockInMemoryRepository::new());
+        let transaction_repository = Arc::new(TransactionInMemoryRepository::new());
+        let wallet_repository = Arc::new(WalletInMemoryRepository::new());
+        let fungible_token_repository = Arc::new(FungibleTokenInMemoryRepository::new());
+        let vesting_repository = Arc::new(VestingInMemoryRepository::new());
+        Storage {
+            db_pool: None,
+            schema_service: Arc::new(SchemaInMemoryServiceImpl::new()),
+            service_context_service: Arc::new(ServiceContextInMemoryServiceImpl::new()),
+            block_repository: block_repository.clone(),
+            transaction_repository: transaction_repository.clone(),
+            wallet_repository: wallet_repository.clone(),
+            fungible_token_repository: fungible_token_repository.clone(),
+            vesting_repository: vesting_repository.clone(),
+            ledger_repository: Arc::new(LedgerInMemoryRepository::new(
+                block_repository,
+                transaction_repository,
+                fungible_token_repository,
+                vesting_repository,
+                wallet_repository,
+            )),
+        }
+    }
+}
+
 pub struct Container {
+    pub schema_service: Arc<dyn SchemaService>,
     pub service_context_service: Arc<dyn ServiceContextService>,
     pub transaction_service: Arc<dyn TransactionService>,
     pub block_service: Arc<dyn BlockService>,
     pub wallet_service: Arc<dyn WalletService>,
     pub fungible_token_service: Arc<dyn FungibleTokenService>,
+    pub vesting_service: Arc<dyn VestingService>,
+    pub chain_service: Arc<dyn ChainService>,
+    pub health_service: Arc<dyn HealthService>,
+    pub metrics_service: Arc<dyn MetricsService>,
+    pub mempool_service: Arc<dyn MempoolService>,
+    pub event_bus: Arc<dyn EventBus>,
 }
 
 impl Container {
     pub fn new() -> Self {
-        let db_pool = Arc::new(db_pool()); // Create the database pool only once
+        Self::with_storage(StorageBackend::from_env())
+    }
 
-        let service_context_service = Arc::new(ServiceContextServiceImpl::new(Arc::clone(&db_pool)));
+    pub fn with_storage(storage_backend: StorageBackend) -> Self {
+        let storage = match storage_backend {
+            StorageBackend::Postgresql => Storage::diesel(postgresql::db_pool()),
+            StorageBackend::Sqlite => Storage::diesel(sqlite::db_pool()),
+            StorageBackend::InMemory => Storage::in_memory(),
+        };
+        Self::build(storage, load_genesis())
+    }
 
-        let wallet_repository: Arc<dyn WalletRepository> = Arc::new(WalletDieselRepository::new(Arc::clone(&db_pool)));
+    // Wires the Diesel repositories on an already migrated pool, e.g. an isolated database per test
+    pub fn with_db_pool(db_pool: DBConn, genesis: Genesis) -> Self {
+        Self::build(Storage::diesel(db_pool), genesis)
+    }
 
-        let wallet_service = Arc::new(WalletServiceImpl { repository: wallet_repository });
+    pub fn in_memory(genesis: Genesis) -> Self {
+        Self::build(Storage::in_memory(), genesis)
+    }
 
-        let fungible_token_repository: Arc<dyn FungibleTokenRepository> = Arc::new(FungibleTokenDieselRepository::new(Arc::clone(&db_pool)));
+    fn build(storage: Storage, genesis: Genesis) -> Self {
+        let schema_service = storage.schema_service;
+        let service_context_service = storage.service_context_service;
 
-        let fungible_token_service = Arc::new(FungibleTokenServiceImpl {
-            repository: fungible_token_repository,
+        let wallet_service = Arc::new(WalletServiceImpl {
+            repository: storage.wallet_repository,
         });
 
-        let transaction_repository: Arc<dyn TransactionRepository> = Arc::new(TransactionDieselRepository::new(Arc::clone(&db_pool)));
+        let fungible_token_service = Arc::new(FungibleTokenServiceImpl::new(storage.fungible_token_repository, reserved_token_symbols()));
+
+        let vesting_service = Arc::new(VestingServiceImpl::new(storage.vesting_repository));
+
+        let ledger_service = Arc::new(LedgerServiceImpl::new(storage.ledger_repository));
+
+        let block_repository = storage.block_repository;
+
+        let chain_service = Arc::new(ChainServiceImpl::new(genesis, block_repository.clone(), schema_service.clone(), ledger_service.clone()));
+
+        let metrics_service = Arc::new(PrometheusMetricsServiceImpl::new(
+            storage.db_pool,
+            chain_service.clone(),
+            storage.transaction_repository.clone(),
+            fungible_token_service.clone(),
+        ));
+
+        let event_bus: Arc<dyn EventBus> = Arc::new(BroadcastEventBus::default());
+
+        let mempool_service = Arc::new(MempoolServiceImpl::new(
+            storage.transaction_repository.clone(),
+            event_bus.clone(),
+            mempool_transaction_ttl(),
+            mempool_max_pending_per_sender(),
+        ));
 
         let transaction_service = Arc::new(TransactionServiceImpl {
-            repository: transaction_repository,
+            repository: storage.transaction_repository,
             wallet_service: wallet_service.clone(),
             fungible_token_service: fungible_token_service.clone(),
+            metrics_service: metrics_service.clone(),
+            mempool_service: mempool_service.clone(),
+            vesting_service: vesting_service.clone(),
+            ledger_service: ledger_service.clone(),
+            idempotency_key_ttl: idempotency_key_ttl(),
         });
 
-        let block_repository: Arc<dyn BlockRepository> = Arc::new(BlockDieselRepository::new(Arc::clone(&db_pool)));
+        let block_service = Arc::new(BlockServiceImpl::new(
+            block_repository,
+            transaction_service.clone(),
+            mempool_service.clone(),
+            wallet_service.clone(),
+            chain_service.clone(),
+            metrics_service.clone(),
+            event_bus.clone(),
+        ));
 
-        let block_service = Arc::new(BlockServiceImpl {
-            repository: block_repository,
-            transaction_service: transaction_service.clone(),
-            wallet_service: wallet_service.clone(),
-        });
+        let health_service = Arc::new(HealthServiceImpl::new(
+            schema_service.clone(),
+            chain_service.clone(),
+            block_service.clone(),
+            transaction_service.clone(),
+            block_producer_max_idle(),
+        ));
 
         Container {
+            schema_service,
             service_context_service,
             transaction_service,
             block_service,
             wallet_service,
             fungible_token_service,
+            vesting_service,
+            chain_service,
+            health_service,
+            metrics_service,
+            mempool_service,
+            event_bus,
         }
     }
 }
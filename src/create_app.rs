@@ -1,29 +1,46 @@
 use crate::api::controllers::block_handler::{create_block_handler, get_block_handler, list_block_handler};
-use crate::api::controllers::fungible_token::{get_ft_handler, list_ft_handler};
+use crate::api::controllers::chain_handler::get_chain_handler;
+use crate::api::controllers::fungible_token::{get_ft_by_symbol_handler, get_ft_handler, get_ft_ownership_history_handler, list_ft_handler};
+use crate::api::controllers::graphql_handler::{graphql_handler, graphql_playground_handler};
+use crate::api::controllers::health_handler::{info_handler, liveness_handler, readiness_handler};
+use crate::api::controllers::metrics_handler::metrics_handler;
+use crate::api::controllers::rpc_handler::rpc_handler;
 use crate::api::controllers::service_context_handler::{get_service_context_handler, update_service_context_handler};
-use crate::api::controllers::transaction_handler::{create_transaction_handler, delete_transaction_handler, get_transaction_handler, list_transaction_handler};
+use crate::api::controllers::stream_handler::{block_stream_handler, transaction_stream_handler};
+use crate::api::controllers::subscription_handler::subscription_handler;
+use crate::api::controllers::transaction_handler::{
+    cancel_transaction_handler, create_transaction_batch_handler, create_transaction_handler, get_transaction_handler, list_transaction_handler, simulate_transaction_handler,
+};
+use crate::api::controllers::vesting_handler::get_account_vesting_handler;
 use crate::api::controllers::wallet_handler::{get_wallet_handler, list_wallet_handler};
 
-use crate::api::middleware::ServiceContextMaintenanceCheck;
+use crate::api::graphql::build_schema;
+use crate::api::middleware::{HttpMetrics, RequestIdHeader, ServiceContextMaintenanceCheck};
 use crate::container::Container;
 use crate::open_api::ApiDoc;
 use actix_web::body::MessageBody;
 use actix_web::dev::{ServiceFactory, ServiceRequest, ServiceResponse};
-use actix_web::middleware::Logger;
 use actix_web::{web, App, Error};
+use std::sync::Arc;
+use tracing_actix_web::TracingLogger;
 
 use utoipa::OpenApi;
 use utoipa_rapidoc::RapiDoc;
 use utoipa_redoc::{Redoc, Servable};
 use utoipa_swagger_ui::SwaggerUi;
 
-pub fn create_app() -> App<impl ServiceFactory<ServiceRequest, Response = ServiceResponse<impl MessageBody>, Config = (), InitError = (), Error = Error>> {
-    let container = Container::new();
+pub fn create_app(container: Arc<Container>) -> App<impl ServiceFactory<ServiceRequest, Response = ServiceResponse<impl MessageBody>, Config = (), InitError = (), Error = Error>> {
     let service_context_service = container.service_context_service.clone();
     let transaction_service = container.transaction_service.clone();
     let block_service = container.block_service.clone();
     let wallet_service = container.wallet_service.clone();
     let fungible_token_service = container.fungible_token_service.clone();
+    let vesting_service = container.vesting_service.clone();
+    let chain_service = container.chain_service.clone();
+    let health_service = container.health_service.clone();
+    let metrics_service = container.metrics_service.clone();
+    let event_bus = container.event_bus.clone();
+    let graphql_schema = build_schema(&container);
     let openapi = ApiDoc::openapi();
 
     App::new()
@@ -32,13 +49,22 @@ pub fn create_app() -> App<impl ServiceFactory<ServiceRequest, Response = Servic
         .app_data(web::Data::from(block_service.clone()))
         .app_data(web::Data::from(wallet_service.clone()))
         .app_data(web::Data::from(fungible_token_service.clone()))
+        .app_data(web::Data::from(vesting_service.clone()))
+        .app_data(web::Data::from(chain_service.clone()))
+        .app_data(web::Data::from(health_service.clone()))
+        .app_data(web::Data::from(metrics_service.clone()))
+        .app_data(web::Data::from(event_bus.clone()))
+        .app_data(web::Data::new(graphql_schema))
         .wrap(ServiceContextMaintenanceCheck)
+        .wrap(HttpMetrics)
         .service(
             web::scope("/api/transactions")
                 .route("", web::post().to(create_transaction_handler))
+                .route("/batch", web::post().to(create_transaction_batch_handler))
+                .route("/simulate", web::post().to(simulate_transaction_handler))
                 .route("", web::get().to(list_transaction_handler))
                 .route("/{id}", web::get().to(get_transaction_handler))
-                .route("/{id}", web::delete().to(delete_transaction_handler)),
+                .route("/{transaction_hash}/cancel", web::post().to(cancel_transaction_handler)),
         )
         .service(
             web::scope("/api/blocks")
@@ -51,11 +77,26 @@ pub fn create_app() -> App<impl ServiceFactory<ServiceRequest, Response = Servic
                 .route("", web::get().to(list_wallet_handler))
                 .route("/{wallet_address}/{token_address}", web::get().to(get_wallet_handler)),
         )
+        .service(web::scope("/api/accounts").route("/{address}/vesting", web::get().to(get_account_vesting_handler)))
         .service(
             web::scope("/api/fts")
                 .route("", web::get().to(list_ft_handler))
-                .route("/{token_address}", web::get().to(get_ft_handler)),
+                .route("/by-symbol/{symbol}", web::get().to(get_ft_by_symbol_handler))
+                .route("/{token_address}", web::get().to(get_ft_handler))
+                .route("/{token_address}/ownership-history", web::get().to(get_ft_ownership_history_handler)),
         )
+        .route("/api/ws", web::get().to(subscription_handler))
+        .route("/rpc", web::post().to(rpc_handler))
+        .service(web::resource("/graphql").route(web::post().to(graphql_handler)).route(web::get().to(graphql_handler)))
+        .service(
+            web::scope("/api/stream")
+                .route("/blocks", web::get().to(block_stream_handler))
+                .route("/transactions", web::get().to(transaction_stream_handler)),
+        )
+        .service(web::scope("/api/chain").route("", web::get().to(get_chain_handler)))
+        .service(web::scope("/health").route("/live", web::get().to(liveness_handler)).route("/ready", web::get().to(readiness_handler)))
+        .route("/info", web::get().to(info_handler))
+        .route("/metrics", web::get().to(metrics_handler))
         .service(
             web::scope("/admin")
                 .route("/maintenance/status", web::post().to(update_service_context_handler))
@@ -64,5 +105,7 @@ pub fn create_app() -> App<impl ServiceFactory<ServiceRequest, Response = Servic
         .service(Redoc::with_url("/redoc", openapi.clone()))
         .service(SwaggerUi::new("/swagger-ui/{_:.*}").url("/api-docs/openapi.json", openapi.clone()))
         .service(RapiDoc::new("/api-docs/openapi.json").path("/rapidoc"))
-        .wrap(Logger::default())
+        .route("/playground", web::get().to(graphql_playground_handler))
+        .wrap(RequestIdHeader)
+        .wrap(TracingLogger::default())
 }
@@ -1,2 +1,24 @@
 pub const POSTGRESQL_DB_URI: &str = "DATABASE_URL";
 pub const POSTGRESQL_DB_POOL_SIZE_PER_WORKER: &str = "MAX_DB_SESSIONS_PER_WORKER";
+pub const SQLITE_DB_URI: &str = "SQLITE_DATABASE_URL";
+pub const STORAGE_BACKEND: &str = "STORAGE_BACKEND";
+pub const RUN_MIGRATIONS: &str = "RUN_MIGRATIONS";
+pub const BLOCK_PRODUCER_MAX_IDLE_SECONDS: &str = "BLOCK_PRODUCER_MAX_IDLE_SECONDS";
+pub const IDEMPOTENCY_KEY_TTL_SECONDS: &str = "IDEMPOTENCY_KEY_TTL_SECONDS";
+pub const DEFAULT_IDEMPOTENCY_KEY_TTL_SECONDS: i64 = 24 * 60 * 60;
+pub const MAX_TRANSACTION_BATCH_SIZE: usize = 1000;
+pub const MEMPOOL_TRANSACTION_TTL_SECONDS: &str = "MEMPOOL_TRANSACTION_TTL_SECONDS";
+pub const DEFAULT_MEMPOOL_TRANSACTION_TTL_SECONDS: i64 = 60 * 60;
+pub const MEMPOOL_MAX_PENDING_PER_SENDER: &str = "MEMPOOL_MAX_PENDING_PER_SENDER";
+pub const DEFAULT_MEMPOOL_MAX_PENDING_PER_SENDER: i64 = 64;
+pub const RESERVED_TOKEN_SYMBOLS: &str = "RESERVED_TOKEN_SYMBOLS";
+pub const MAX_TRANSACTIONS_PER_BLOCK: usize = 2;
+pub const MAX_MULTI_TRANSFER_RECIPIENTS: usize = 100;
+pub const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+pub const GIT_COMMIT: &str = env!("GIT_COMMIT");
+pub const LOG_FORMAT: &str = "LOG_FORMAT";
+pub const OTLP_ENDPOINT: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+pub const GENESIS_FILE: &str = "GENESIS_FILE";
+pub const GENESIS_BLOCK_NUMBER: i32 = 0;
+pub const GENESIS_PARENT_HASH: &str = "0x0000000000000000000000000000000000000000000000000000000000000000";
+pub const ZERO_ADDRESS: &str = "0x0000000000000000000000000000000000000000";
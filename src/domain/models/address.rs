@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use crate::domain::constants::ZERO_ADDRESS;
+use crate::domain::error::CommonError;
+
+// A 0x-prefixed 20-byte hex address, kept lowercase whatever the case it was given in
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Address(String);
+
+impl Address {
+    pub fn zero() -> Self {
+        Address(ZERO_ADDRESS.to_string())
+    }
+
+    pub fn from_bytes(bytes: &[u8; 20]) -> Self {
+        Address(format!("0x{}", hex::encode(bytes)))
+    }
+
+    // Only for addresses read back from storage, which are written validated and lowercase, see the normalize_addresses migration for older rows
+    pub fn new_unchecked(address: String) -> Self {
+        Address(address)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    // EIP-55 mixed-case encoding, the letters whose nibble in the hash of the lowercase hex is 8 or more are uppercased.
+    // Only checked against the mixed-case addresses given, addresses are always returned lowercase
+    fn to_checksum(&self) -> String {
+        let hex_digits = &self.0[2..];
+        let hash = Keccak256::digest(hex_digits.as_bytes());
+        let checksummed: String = hex_digits
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                let nibble = if i % 2 == 0 { hash[i / 2] >> 4 } else { hash[i / 2] & 0x0f };
+                if nibble >= 8 {
+                    c.to_ascii_uppercase()
+                } else {
+                    c
+                }
+            })
+            .collect();
+        format!("0x{}", checksummed)
+    }
+}
+
+fn invalid_address(address: &str, reason: &str) -> CommonError {
+    CommonError {
+        message: format!("Invalid address {}: {}", address, reason),
+        code: 3,
+    }
+}
+
+impl FromStr for Address {
+    type Err = CommonError;
+
+    // Accepts all-lowercase and all-uppercase hex, a mixed-case address must carry a valid EIP-55 checksum
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(hex_digits) = s.strip_prefix("0x") else {
+            return Err(invalid_address(s, "expected a 0x prefix"));
+        };
+        if hex_digits.len() != 40 || !hex_digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid_address(s, "expected 40 hex digits"));
+        }
+        let address = Address(format!("0x{}", hex_digits.to_ascii_lowercase()));
+        let mixed_case = hex_digits.chars().any(|c| c.is_ascii_lowercase()) && hex_digits.chars().any(|c| c.is_ascii_uppercase());
+        if mixed_case && address.to_checksum() != s {
+            return Err(invalid_address(s, "checksum mismatch"));
+        }
+        Ok(address)
+    }
+}
+
+impl TryFrom<String> for Address {
+    type Error = CommonError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Address> for String {
+    fn from(address: Address) -> String {
+        address.0
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for Address {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for Address {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Address {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
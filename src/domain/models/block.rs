@@ -1,20 +1,23 @@
 use serde::Deserialize;
 
+use super::address::Address;
+
 #[derive(Clone, Deserialize)]
 pub struct Block {
     pub block_number: i32,
     pub block_hash: String,
     pub parent_hash: String,
     pub timestamp: Option<chrono::NaiveDateTime>,
-    pub miner_address: String,
+    pub miner_address: Address,
     pub transaction_count: i32,
 }
 
 #[derive(Clone)]
 pub struct CreateBlock {
+    pub block_number: Option<i32>,
     pub block_hash: String,
     pub parent_hash: String,
     pub timestamp: Option<chrono::NaiveDateTime>,
-    pub miner_address: String,
+    pub miner_address: Address,
     pub transaction_count: i32,
 }
@@ -0,0 +1,12 @@
+use serde::Deserialize;
+
+use super::address::Address;
+
+#[derive(Clone, Deserialize)]
+pub struct ChainInfo {
+    pub chain_id: String,
+    pub genesis_hash: String,
+    pub authorities: Vec<Address>,
+    pub block_height: i32,
+    pub schema_version: Option<String>,
+}
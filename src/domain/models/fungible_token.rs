@@ -1,18 +1,57 @@
 use serde::Deserialize;
 
+use super::address::Address;
+use crate::domain::constants::ZERO_ADDRESS;
+
 #[derive(Clone, Deserialize)]
 pub struct FungibleToken {
-    pub address: String,
+    pub address: Address,
     pub symbol: String,
     pub name: String,
-    pub owner_address: String,
+    pub owner_address: Address,
     pub decimals: i32,
     pub total_supply: i64,
     pub block_number: i32,
     pub transaction_hash: String,
+    // Addresses allowed to mint besides the owner
+    #[serde(default)]
+    pub minter_addresses: Vec<Address>,
+    // Uncapped when None
+    pub max_supply: Option<i64>,
+    pub mintable: bool,
+    pub burnable: bool,
+    // A paused token can be neither transferred, minted nor burnt
+    pub paused: bool,
+}
+
+impl FungibleToken {
+    // A renounced token is owned by the zero address, nobody can administer it anymore
+    pub fn is_renounced(&self) -> bool {
+        self.owner_address == ZERO_ADDRESS
+    }
+
+    pub fn is_owner(&self, address: &str) -> bool {
+        !self.is_renounced() && self.owner_address == address
+    }
+
+    pub fn can_mint(&self, address: &str) -> bool {
+        self.is_owner(address) || self.minter_addresses.iter().any(|minter_address| minter_address == address)
+    }
 }
 
 #[derive(Clone)]
 pub struct UpdatedFungibleToken {
     pub total_supply: Option<i64>,
+    pub minter_addresses: Option<Vec<Address>>,
+    pub paused: Option<bool>,
+}
+
+// An entry of the ownership history of a token, the new owner is the zero address when the ownership was renounced
+#[derive(Clone)]
+pub struct OwnershipChange {
+    pub token_address: Address,
+    pub previous_owner_address: Address,
+    pub new_owner_address: Address,
+    pub block_number: i32,
+    pub transaction_hash: String,
 }
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use super::address::Address;
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Genesis {
+    pub chain_id: String,
+    pub timestamp: Option<chrono::NaiveDateTime>,
+    #[serde(default)]
+    pub authorities: Vec<Address>,
+    #[serde(default)]
+    pub fungible_tokens: Vec<GenesisFungibleToken>,
+    #[serde(default)]
+    pub balances: Vec<GenesisBalance>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GenesisFungibleToken {
+    pub address: Address,
+    pub symbol: String,
+    pub name: String,
+    pub owner_address: Address,
+    pub decimals: i32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GenesisBalance {
+    pub address: Address,
+    pub token_address: Address,
+    pub balance: i64,
+}
@@ -0,0 +1,24 @@
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct HealthCheck {
+    pub name: String,
+    pub healthy: bool,
+    pub message: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct HealthReport {
+    pub healthy: bool,
+    pub checks: Vec<HealthCheck>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct NodeInfo {
+    pub version: String,
+    pub git_commit: String,
+    pub chain_id: String,
+    pub block_height: i32,
+    pub mempool_size: i64,
+    pub schema_version: Option<String>,
+}
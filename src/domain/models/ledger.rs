@@ -0,0 +1,29 @@
+use super::block::CreateBlock;
+use super::fungible_token::{FungibleToken, OwnershipChange, UpdatedFungibleToken};
+use super::transaction::UpdateTransaction;
+use super::vesting::CreateVestingSchedule;
+use super::wallet::Wallet;
+use crate::domain::error::RepositoryError;
+
+// Writes stored together in a single database transaction, so the ledger never keeps only part of them
+#[derive(Clone, Default)]
+pub struct LedgerChanges {
+    // Only the genesis commits its block along with the state it creates
+    pub block: Option<CreateBlock>,
+    // The executed transaction is marked mined along with its effects, and the commit fails unless it is still pending
+    pub transaction: Option<(i32, UpdateTransaction)>,
+    pub created_tokens: Vec<FungibleToken>,
+    // Keyed by token address
+    pub updated_tokens: Vec<(String, UpdatedFungibleToken)>,
+    pub ownership_changes: Vec<OwnershipChange>,
+    pub created_vesting_schedules: Vec<CreateVestingSchedule>,
+    // The new claimed amount of every schedule, keyed by schedule id
+    pub vesting_claims: Vec<(i32, i64)>,
+    pub wallets: Vec<Wallet>,
+}
+
+pub fn no_longer_pending(transaction_id: i32) -> RepositoryError {
+    RepositoryError {
+        message: format!("Transaction {} is no longer pending", transaction_id),
+    }
+}
@@ -0,0 +1,54 @@
+use super::address::Address;
+use super::block::Block;
+use super::transaction::Transaction;
+use super::wallet::Wallet;
+
+// State changes pushed to subscribers once they are persisted
+#[derive(Clone)]
+pub enum LedgerEvent {
+    NewBlock(Block),
+    PendingTransaction(Transaction),
+    TransactionStatus(Transaction),
+    WalletChanged(Wallet),
+}
+
+impl LedgerEvent {
+    // Name of the subscription topic the event is published on
+    pub fn topic(&self) -> &'static str {
+        match self {
+            LedgerEvent::NewBlock(_) => "newBlocks",
+            LedgerEvent::PendingTransaction(_) => "pendingTransactions",
+            LedgerEvent::TransactionStatus(_) => "transactionStatus",
+            LedgerEvent::WalletChanged(_) => "walletChanges",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubscriptionTopic {
+    NewBlocks,
+    PendingTransactions,
+    TransactionStatus { transaction_hash: String },
+    WalletChanges { address: Address },
+}
+
+impl SubscriptionTopic {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubscriptionTopic::NewBlocks => "newBlocks",
+            SubscriptionTopic::PendingTransactions => "pendingTransactions",
+            SubscriptionTopic::TransactionStatus { .. } => "transactionStatus",
+            SubscriptionTopic::WalletChanges { .. } => "walletChanges",
+        }
+    }
+
+    pub fn matches(&self, event: &LedgerEvent) -> bool {
+        match (self, event) {
+            (SubscriptionTopic::NewBlocks, LedgerEvent::NewBlock(_)) => true,
+            (SubscriptionTopic::PendingTransactions, LedgerEvent::PendingTransaction(_)) => true,
+            (SubscriptionTopic::TransactionStatus { transaction_hash }, LedgerEvent::TransactionStatus(transaction)) => transaction.transaction_hash.eq_ignore_ascii_case(transaction_hash),
+            (SubscriptionTopic::WalletChanges { address }, LedgerEvent::WalletChanged(wallet)) => wallet.address == *address,
+            _ => false,
+        }
+    }
+}
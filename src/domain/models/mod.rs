@@ -1,6 +1,14 @@
+pub mod address;
 pub mod block;
+pub mod chain;
 pub mod fungible_token;
+pub mod genesis;
+pub mod health;
+pub mod ledger;
+pub mod ledger_event;
+pub mod schema;
 pub mod service_context;
 pub mod transaction;
 pub mod transaction_type;
+pub mod vesting;
 pub mod wallet;
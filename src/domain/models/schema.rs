@@ -0,0 +1,8 @@
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct SchemaStatus {
+    pub version: Option<String>,
+    pub applied_migrations: usize,
+    pub pending_migrations: usize,
+}
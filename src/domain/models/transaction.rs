@@ -3,28 +3,43 @@ use serde_json::Value;
 use std::fmt;
 use std::str::FromStr;
 
+use super::address::Address;
+use super::ledger_event::LedgerEvent;
 use super::transaction_type::TransactionType;
+use super::wallet::Wallet;
+use crate::utils::hex_utils::derive_token_address;
 
 #[derive(Clone, Deserialize)]
 pub struct Transaction {
     pub id: i32,
     pub block_number: Option<i32>,
     pub transaction_hash: String,
-    pub from_address: String,
-    pub to_address: String,
+    pub from_address: Address,
+    pub to_address: Address,
     pub transaction_type: TransactionType,
     pub value: i64,
     pub timestamp: Option<chrono::NaiveDateTime>,
     pub data: Option<Value>,
     pub is_mined: Option<bool>,
     pub status: TransactionStatus,
+    pub idempotency_key: Option<String>,
+    pub fee: i64,
+    pub nonce: i64,
+    // Address of the token created by a successful INIT_FT, stored when it is mined
+    pub created_token_address: Option<Address>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
 pub enum TransactionStatus {
     RAW,
     SUCCESS,
     FAIL,
+    // Dropped from the mempool, unmined once MEMPOOL_TRANSACTION_TTL_SECONDS elapsed
+    EXPIRED,
+    // Dropped from the mempool for a transaction of the same sender and nonce paying a higher fee
+    REPLACED,
+    // Withdrawn by its sender while pending, kept for audit
+    CANCELLED,
 }
 
 impl fmt::Display for TransactionStatus {
@@ -41,6 +56,9 @@ impl FromStr for TransactionStatus {
             "RAW" => Ok(TransactionStatus::RAW),
             "SUCCESS" => Ok(TransactionStatus::SUCCESS),
             "FAIL" => Ok(TransactionStatus::FAIL),
+            "EXPIRED" => Ok(TransactionStatus::EXPIRED),
+            "REPLACED" => Ok(TransactionStatus::REPLACED),
+            "CANCELLED" => Ok(TransactionStatus::CANCELLED),
             _ => Err(()),
         }
     }
@@ -49,12 +67,16 @@ impl FromStr for TransactionStatus {
 #[derive(Debug, Clone)]
 pub struct CreateTransaction {
     pub transaction_hash: String,
-    pub from_address: String,
-    pub to_address: String,
+    pub from_address: Address,
+    pub to_address: Address,
     pub transaction_type: TransactionType,
     pub value: i64,
     pub timestamp: Option<chrono::NaiveDateTime>,
     pub data: Option<Value>,
+    pub idempotency_key: Option<String>,
+    pub fee: i64,
+    // The next nonce of the sender when None, resolved by the mempool on admission
+    pub nonce: Option<i64>,
 }
 
 #[derive(Clone)]
@@ -62,4 +84,47 @@ pub struct UpdateTransaction {
     pub block_number: Option<i32>,
     pub is_mined: Option<bool>,
     pub status: Option<TransactionStatus>,
+    pub created_token_address: Option<Address>,
+}
+
+impl Transaction {
+    // Waiting in the mempool to be mined
+    pub fn is_pending(&self) -> bool {
+        self.is_mined != Some(true) && self.status == TransactionStatus::RAW
+    }
+
+    // Address of the token an INIT_FT creates, predicted from its sender and nonce while pending and the stored one once mined
+    pub fn token_address(&self) -> Option<Address> {
+        if self.transaction_type == TransactionType::InitFt && self.is_pending() {
+            return Some(derive_token_address(&self.from_address, self.nonce));
+        }
+        self.created_token_address.clone()
+    }
+}
+
+// Outcome of executing a transaction in a block, with the wallets it touched
+#[derive(Clone)]
+pub struct TransactionReceipt {
+    pub transaction: Transaction,
+    pub updated_wallets: Vec<Wallet>,
+    // Address of the token created by a successful INIT_FT
+    pub token_address: Option<Address>,
+}
+
+// Balance of a wallet before and after a simulated transaction
+#[derive(Clone)]
+pub struct BalanceDelta {
+    pub address: Address,
+    pub token_address: Address,
+    pub balance_before: i64,
+    pub balance_after: i64,
+}
+
+// Outcome a transaction would have if it were mined now, nothing of it is persisted
+#[derive(Clone)]
+pub struct TransactionSimulation {
+    pub status: TransactionStatus,
+    pub error: Option<String>,
+    pub balance_deltas: Vec<BalanceDelta>,
+    pub events: Vec<LedgerEvent>,
 }
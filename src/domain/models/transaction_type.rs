@@ -1,17 +1,34 @@
-use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::str::FromStr;
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Deserialize)]
+use super::address::Address;
+use super::vesting::VestingUnit;
+use crate::domain::error::CommonError;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 pub enum TransactionType {
     InitFt,
     MintFt,
     BurnFt,
     TransferFt,
+    MultiTransferFt,
+    TransferOwnershipFt,
+    RenounceOwnershipFt,
+    GrantMinterFt,
+    RevokeMinterFt,
+    PauseFt,
+    UnpauseFt,
+    FreezeAccount,
+    UnfreezeAccount,
+    VestingTransferFt,
+    ClaimVestedFt,
     InitNft,
     MintNft,
     BurnNft,
     TransferNft,
-    None,
 }
 
 impl TransactionType {
@@ -21,87 +38,164 @@ impl TransactionType {
             TransactionType::MintFt => "MINT_FT",
             TransactionType::BurnFt => "BURN_FT",
             TransactionType::TransferFt => "TRANSFER_FT",
+            TransactionType::MultiTransferFt => "MULTI_TRANSFER_FT",
+            TransactionType::TransferOwnershipFt => "TRANSFER_OWNERSHIP_FT",
+            TransactionType::RenounceOwnershipFt => "RENOUNCE_OWNERSHIP_FT",
+            TransactionType::GrantMinterFt => "GRANT_MINTER_FT",
+            TransactionType::RevokeMinterFt => "REVOKE_MINTER_FT",
+            TransactionType::PauseFt => "PAUSE_FT",
+            TransactionType::UnpauseFt => "UNPAUSE_FT",
+            TransactionType::FreezeAccount => "FREEZE_ACCOUNT",
+            TransactionType::UnfreezeAccount => "UNFREEZE_ACCOUNT",
+            TransactionType::VestingTransferFt => "VESTING_TRANSFER_FT",
+            TransactionType::ClaimVestedFt => "CLAIM_VESTED_FT",
             TransactionType::InitNft => "INIT_NFT",
             TransactionType::MintNft => "MINT_NFT",
             TransactionType::BurnNft => "BURN_NFT",
             TransactionType::TransferNft => "TRANSFER_NFT",
-            TransactionType::None => "",
         }
     }
+}
+
+impl FromStr for TransactionType {
+    type Err = CommonError;
 
-    pub fn from_str(s: &str) -> TransactionType {
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "INIT_FT" => TransactionType::InitFt,
-            "MINT_FT" => TransactionType::MintFt,
-            "BURN_FT" => TransactionType::BurnFt,
-            "TRANSFER_FT" => TransactionType::TransferFt,
-            "INIT_NFT" => TransactionType::InitNft,
-            "MINT_NFT" => TransactionType::MintNft,
-            "BURN_NFT" => TransactionType::BurnNft,
-            "TRANSFER_NFT" => TransactionType::TransferNft,
-            _ => TransactionType::None,
+            "INIT_FT" => Ok(TransactionType::InitFt),
+            "MINT_FT" => Ok(TransactionType::MintFt),
+            "BURN_FT" => Ok(TransactionType::BurnFt),
+            "TRANSFER_FT" => Ok(TransactionType::TransferFt),
+            "MULTI_TRANSFER_FT" => Ok(TransactionType::MultiTransferFt),
+            "TRANSFER_OWNERSHIP_FT" => Ok(TransactionType::TransferOwnershipFt),
+            "RENOUNCE_OWNERSHIP_FT" => Ok(TransactionType::RenounceOwnershipFt),
+            "GRANT_MINTER_FT" => Ok(TransactionType::GrantMinterFt),
+            "REVOKE_MINTER_FT" => Ok(TransactionType::RevokeMinterFt),
+            "PAUSE_FT" => Ok(TransactionType::PauseFt),
+            "UNPAUSE_FT" => Ok(TransactionType::UnpauseFt),
+            "FREEZE_ACCOUNT" => Ok(TransactionType::FreezeAccount),
+            "UNFREEZE_ACCOUNT" => Ok(TransactionType::UnfreezeAccount),
+            "VESTING_TRANSFER_FT" => Ok(TransactionType::VestingTransferFt),
+            "CLAIM_VESTED_FT" => Ok(TransactionType::ClaimVestedFt),
+            "INIT_NFT" => Ok(TransactionType::InitNft),
+            "MINT_NFT" => Ok(TransactionType::MintNft),
+            "BURN_NFT" => Ok(TransactionType::BurnNft),
+            "TRANSFER_NFT" => Ok(TransactionType::TransferNft),
+            _ => Err(CommonError {
+                message: format!("Unknown transaction type {}", s),
+                code: 3,
+            }),
         }
     }
 }
 
+// Reads the metadata of a transaction type out of the data of a transaction, rejecting missing, unknown or mistyped fields
+pub fn parse_metadata<T: DeserializeOwned>(data: Option<&Value>) -> Result<T, CommonError> {
+    let data = data.ok_or_else(|| CommonError {
+        message: "Data is missing.".to_string(),
+        code: 3,
+    })?;
+    T::deserialize(data).map_err(|e| CommonError {
+        message: format!("Malformed data: {}.", e),
+        code: 3,
+    })
+}
+
+fn enabled() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct InitFt {
     pub symbol: String,
     pub name: String,
+    // Defaults to 0
+    #[serde(default)]
     pub decimals: i32,
+    // Uncapped when absent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_supply: Option<i64>,
+    // Both default to true
+    #[serde(default = "enabled")]
+    pub mintable: bool,
+    #[serde(default = "enabled")]
+    pub burnable: bool,
 }
 
-impl Into<InitFt> for Value {
-    fn into(self) -> InitFt {
-        let symbol = self.get("symbol").and_then(|s| s.as_str()).unwrap_or_default();
-        let name = self.get("name").and_then(|s| s.as_str()).unwrap_or_default();
-        let decimals = self.get("decimals").and_then(|d| d.as_i64()).unwrap_or(0) as i32;
-
-        InitFt {
-            symbol: symbol.to_string(),
-            name: name.to_string(),
-            decimals,
-        }
-    }
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TransferFt {
+    #[schema(value_type = String)]
+    pub token_address: Address,
 }
 
-pub struct TransferFt {
-    pub token_address: String,
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MultiTransferFt {
+    #[schema(value_type = String)]
+    pub token_address: Address,
+    pub transfers: Vec<Transfer>,
 }
 
-impl Into<TransferFt> for Value {
-    fn into(self) -> TransferFt {
-        let token_address = self.get("token_address").and_then(|s| s.as_str()).unwrap_or_default();
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Transfer {
+    #[schema(value_type = String)]
+    pub to_address: Address,
+    pub amount: i64,
+}
 
-        TransferFt {
-            token_address: token_address.to_string(),
-        }
+impl MultiTransferFt {
+    // None when the amounts overflow
+    pub fn total_amount(&self) -> Option<i64> {
+        self.transfers.iter().try_fold(0i64, |total, transfer| total.checked_add(transfer.amount))
     }
 }
 
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct MintFt {
-    pub token_address: String,
+    #[schema(value_type = String)]
+    pub token_address: Address,
 }
 
-impl Into<MintFt> for Value {
-    fn into(self) -> MintFt {
-        let token_address = self.get("token_address").and_then(|s| s.as_str()).unwrap_or_default();
-
-        MintFt {
-            token_address: token_address.to_string(),
-        }
-    }
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BurnFt {
+    #[schema(value_type = String)]
+    pub token_address: Address,
 }
 
-pub struct BurnFt {
-    pub token_address: String,
+// Metadata of the ownership, minter role, pause and freeze transactions, the new owner, the minter or the account is the to_address
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AdministerFt {
+    #[schema(value_type = String)]
+    pub token_address: Address,
 }
 
-impl Into<BurnFt> for Value {
-    fn into(self) -> BurnFt {
-        let token_address = self.get("token_address").and_then(|s| s.as_str()).unwrap_or_default();
+// Locks the value for the to_address, the start defaults to the block height or the time the transaction is mined at
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct VestingTransferFt {
+    #[schema(value_type = String)]
+    pub token_address: Address,
+    // BLOCK or TIMESTAMP, what start, cliff and duration count
+    #[schema(value_type = String)]
+    pub unit: VestingUnit,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<i64>,
+    // Defaults to 0
+    #[serde(default)]
+    pub cliff: i64,
+    pub duration: i64,
+}
 
-        BurnFt {
-            token_address: token_address.to_string(),
-        }
-    }
+// Claims everything vested for the sender across its schedules of the token
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ClaimVestedFt {
+    #[schema(value_type = String)]
+    pub token_address: Address,
 }
@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use super::address::Address;
+
+// What the start, cliff and duration of a schedule count, block heights or seconds since the epoch
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VestingUnit {
+    BLOCK,
+    TIMESTAMP,
+}
+
+impl fmt::Display for VestingUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl FromStr for VestingUnit {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "BLOCK" => Ok(VestingUnit::BLOCK),
+            "TIMESTAMP" => Ok(VestingUnit::TIMESTAMP),
+            _ => Err(()),
+        }
+    }
+}
+
+// Tokens locked for a beneficiary, nothing vests before start + cliff, then the total vests linearly until start + duration
+#[derive(Clone, Debug)]
+pub struct VestingSchedule {
+    pub id: i32,
+    pub token_address: Address,
+    pub grantor_address: Address,
+    pub beneficiary_address: Address,
+    pub unit: VestingUnit,
+    pub start: i64,
+    pub cliff: i64,
+    pub duration: i64,
+    pub total_amount: i64,
+    pub claimed_amount: i64,
+    pub block_number: i32,
+    pub transaction_hash: String,
+}
+
+impl VestingSchedule {
+    // The amount vested at the given block height and time, whichever the unit of the schedule counts
+    pub fn vested_amount(&self, block_number: i32, timestamp: i64) -> i64 {
+        let now = match self.unit {
+            VestingUnit::BLOCK => block_number as i64,
+            VestingUnit::TIMESTAMP => timestamp,
+        };
+        let elapsed = now.saturating_sub(self.start);
+        if elapsed < self.cliff {
+            0
+        } else if elapsed >= self.duration {
+            self.total_amount
+        } else {
+            (self.total_amount as i128 * elapsed as i128 / self.duration as i128) as i64
+        }
+    }
+
+    // Vested but not claimed yet
+    pub fn claimable_amount(&self, block_number: i32, timestamp: i64) -> i64 {
+        self.vested_amount(block_number, timestamp) - self.claimed_amount
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateVestingSchedule {
+    pub token_address: Address,
+    pub grantor_address: Address,
+    pub beneficiary_address: Address,
+    pub unit: VestingUnit,
+    pub start: i64,
+    pub cliff: i64,
+    pub duration: i64,
+    pub total_amount: i64,
+    pub block_number: i32,
+    pub transaction_hash: String,
+}
+
+// A schedule with the amount it vested at the block height and time it was looked at
+#[derive(Clone, Debug)]
+pub struct VestingStatus {
+    pub schedule: VestingSchedule,
+    pub vested_amount: i64,
+}
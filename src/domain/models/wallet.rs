@@ -1,10 +1,14 @@
 use serde::Deserialize;
 
+use super::address::Address;
+
 #[derive(Clone, Deserialize)]
 pub struct Wallet {
-    pub address: String,
-    pub token_address: String,
+    pub address: Address,
+    pub token_address: Address,
     pub balance: i64,
     pub block_number: i32,
     pub transaction_hash: String,
+    // A frozen wallet can neither send, receive nor burn tokens
+    pub frozen: bool,
 }
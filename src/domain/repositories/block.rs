@@ -22,6 +22,7 @@ impl QueryParams for BlockQueryParams {
 pub trait BlockRepository: Send + Sync {
     async fn create(&self, new_block: &CreateBlock) -> RepositoryResult<Block>;
     async fn list(&self, params: BlockQueryParams) -> RepositoryResult<ResultPaging<Block>>;
+    async fn list_after(&self, block_number: i32, limit: i64) -> RepositoryResult<Vec<Block>>;
     async fn get(&self, block_id: i32) -> RepositoryResult<Block>;
     async fn delete(&self, block_id: i32) -> RepositoryResult<()>;
 }
@@ -1,4 +1,4 @@
-use crate::domain::models::fungible_token::{FungibleToken, UpdatedFungibleToken};
+use crate::domain::models::fungible_token::{FungibleToken, OwnershipChange, UpdatedFungibleToken};
 use crate::domain::repositories::repository::{QueryParams, RepositoryResult, ResultPaging, DEFAULT_LIMIT, DEFAULT_OFFSET};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
@@ -7,6 +7,8 @@ use serde::{Deserialize, Serialize};
 pub struct FungibleTokenQueryParams {
     pub limit: Option<i64>,
     pub offset: Option<i64>,
+    // Case-insensitive start of the token name
+    pub name_prefix: Option<String>,
 }
 
 impl QueryParams for FungibleTokenQueryParams {
@@ -23,5 +25,11 @@ pub trait FungibleTokenRepository: Send + Sync {
     async fn create(&self, new_token: &FungibleToken) -> RepositoryResult<FungibleToken>;
     async fn list(&self, params: FungibleTokenQueryParams) -> RepositoryResult<ResultPaging<FungibleToken>>;
     async fn get(&self, token_address: &str) -> RepositoryResult<FungibleToken>;
+    // Symbols are compared case-insensitively
+    async fn find_by_symbol(&self, symbol: &str) -> RepositoryResult<Option<FungibleToken>>;
     async fn update(&self, token_address: &str, updated_token: UpdatedFungibleToken) -> RepositoryResult<FungibleToken>;
+    // Sets the new owner and records the change in the ownership history together
+    async fn change_owner(&self, change: &OwnershipChange) -> RepositoryResult<FungibleToken>;
+    // Oldest change first
+    async fn list_ownership_changes(&self, token_address: &str) -> RepositoryResult<Vec<OwnershipChange>>;
 }
@@ -0,0 +1,9 @@
+use crate::domain::models::ledger::LedgerChanges;
+use crate::domain::repositories::repository::RepositoryResult;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait LedgerRepository: Send + Sync {
+    // Either every change is stored or none
+    async fn commit(&self, changes: &LedgerChanges) -> RepositoryResult<()>;
+}
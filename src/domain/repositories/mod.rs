@@ -1,5 +1,7 @@
 pub mod block;
 pub mod fungible_token;
+pub mod ledger;
 pub mod repository;
 pub mod transaction;
+pub mod vesting;
 pub mod wallet;
@@ -1,6 +1,7 @@
 use crate::domain::models::transaction::{CreateTransaction, Transaction, UpdateTransaction};
 use crate::domain::repositories::repository::{QueryParams, RepositoryResult, ResultPaging, DEFAULT_LIMIT, DEFAULT_OFFSET};
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -22,8 +23,22 @@ impl QueryParams for TransactionQueryParams {
 #[async_trait]
 pub trait TransactionRepository: Send + Sync {
     async fn create(&self, new_transaction: &CreateTransaction) -> RepositoryResult<Transaction>;
+    // Inserts the transactions and marks the replaced ones REPLACED, failing unless every replaced one is still pending
+    async fn create_many(&self, new_transactions: &[CreateTransaction], replaced_transaction_ids: &[i32]) -> RepositoryResult<Vec<Transaction>>;
     async fn list(&self, params: TransactionQueryParams) -> RepositoryResult<ResultPaging<Transaction>>;
+    async fn list_pending(&self) -> RepositoryResult<Vec<Transaction>>;
+    async fn count_pending(&self, from_address: Option<&str>) -> RepositoryResult<i64>;
+    // Highest nonce of the sender among its mined and pending transactions
+    async fn max_nonce(&self, from_address: &str) -> RepositoryResult<Option<i64>>;
+    async fn list_by_nonce(&self, from_address: &str, nonce: i64) -> RepositoryResult<Vec<Transaction>>;
+    // Marks the pending transactions submitted before the given time EXPIRED and returns them
+    async fn expire_pending(&self, submitted_before: NaiveDateTime) -> RepositoryResult<Vec<Transaction>>;
+    // Marks a pending transaction CANCELLED, failing once it is mined or dropped
+    async fn cancel_pending(&self, transaction_id: i32) -> RepositoryResult<Transaction>;
+    async fn list_by_block(&self, block_number: i32) -> RepositoryResult<Vec<Transaction>>;
     async fn get(&self, transaction_hash: &str) -> RepositoryResult<Transaction>;
-    async fn delete(&self, transaction_id: i32) -> RepositoryResult<()>;
-    async fn update(&self, transaction_id: i32, update_data: UpdateTransaction) -> RepositoryResult<Transaction>;
+    async fn get_by_idempotency_key(&self, from_address: &str, idempotency_key: &str) -> RepositoryResult<Option<Transaction>>;
+    async fn clear_idempotency_key(&self, transaction_id: i32) -> RepositoryResult<()>;
+    // Applies the update only while the transaction is pending, None once it was mined, cancelled or dropped
+    async fn mine_pending(&self, transaction_id: i32, update_data: UpdateTransaction) -> RepositoryResult<Option<Transaction>>;
 }
@@ -0,0 +1,11 @@
+use crate::domain::models::vesting::{CreateVestingSchedule, VestingSchedule};
+use crate::domain::repositories::repository::RepositoryResult;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait VestingRepository: Send + Sync {
+    async fn create(&self, new_schedule: &CreateVestingSchedule) -> RepositoryResult<VestingSchedule>;
+    // Oldest first, every token of the beneficiary unless a token is given
+    async fn list(&self, beneficiary_address: &str, token_address: Option<&str>) -> RepositoryResult<Vec<VestingSchedule>>;
+    async fn update_claimed(&self, schedule_id: i32, claimed_amount: i64) -> RepositoryResult<VestingSchedule>;
+}
@@ -1,3 +1,4 @@
+use crate::domain::models::address::Address;
 use crate::domain::models::wallet::Wallet;
 use crate::domain::repositories::repository::{QueryParams, RepositoryResult, ResultPaging, DEFAULT_LIMIT, DEFAULT_OFFSET};
 use async_trait::async_trait;
@@ -7,6 +8,8 @@ use serde::{Deserialize, Serialize};
 pub struct WalletQueryParams {
     pub limit: Option<i64>,
     pub offset: Option<i64>,
+    pub address: Option<Address>,
+    pub token_address: Option<Address>,
 }
 
 impl QueryParams for WalletQueryParams {
@@ -23,6 +26,7 @@ pub trait WalletRepository: Send + Sync {
     // async fn create(&self, new_block: &CreateBlock) -> RepositoryResult<Block>;
     async fn list(&self, params: WalletQueryParams) -> RepositoryResult<ResultPaging<Wallet>>;
     async fn create_or_update(&self, updated_wallet: &Wallet) -> RepositoryResult<Wallet>;
+    async fn create_or_update_many(&self, updated_wallets: &[Wallet]) -> RepositoryResult<Vec<Wallet>>;
     async fn get(&self, wallet_address: &str, token_address: &str) -> RepositoryResult<Wallet>;
     // async fn delete(&self, block_id: i32) -> RepositoryResult<()>;
 }
@@ -1,14 +1,17 @@
 use async_trait::async_trait;
 
 use crate::domain::error::CommonError;
+use crate::domain::models::address::Address;
 use crate::domain::models::block::Block;
 use crate::domain::repositories::block::BlockQueryParams;
 use crate::domain::repositories::repository::ResultPaging;
 
 #[async_trait]
 pub trait BlockService: Sync + Send {
-    async fn create(&self, miner_address: &str) -> Result<Block, CommonError>;
+    async fn create(&self, miner_address: &Address) -> Result<Block, CommonError>;
     async fn list(&self, params: BlockQueryParams) -> Result<ResultPaging<Block>, CommonError>;
+    // Only the blocks whose events were already published, the others are still being produced
+    async fn list_after(&self, block_number: i32, limit: i64) -> Result<Vec<Block>, CommonError>;
     async fn get(&self, block_id: i32) -> Result<Block, CommonError>;
     async fn delete(&self, block_id: i32) -> Result<(), CommonError>;
 }
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+
+use crate::domain::error::CommonError;
+use crate::domain::models::block::Block;
+use crate::domain::models::chain::ChainInfo;
+
+#[async_trait]
+pub trait ChainService: Sync + Send {
+    async fn init_genesis(&self) -> Result<Block, CommonError>;
+    async fn info(&self) -> Result<ChainInfo, CommonError>;
+    fn is_authority(&self, address: &str) -> bool;
+}
@@ -0,0 +1,8 @@
+use tokio::sync::broadcast::Receiver;
+
+use crate::domain::models::ledger_event::LedgerEvent;
+
+pub trait EventBus: Sync + Send {
+    fn publish(&self, event: LedgerEvent);
+    fn subscribe(&self) -> Receiver<LedgerEvent>;
+}
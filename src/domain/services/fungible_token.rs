@@ -1,7 +1,8 @@
 use async_trait::async_trait;
 
 use crate::domain::error::CommonError;
-use crate::domain::models::fungible_token::FungibleToken;
+use crate::domain::models::address::Address;
+use crate::domain::models::fungible_token::{FungibleToken, OwnershipChange, UpdatedFungibleToken};
 use crate::domain::models::wallet::Wallet;
 use crate::domain::repositories::fungible_token::FungibleTokenQueryParams;
 use crate::domain::repositories::repository::ResultPaging;
@@ -11,6 +12,12 @@ pub trait FungibleTokenService: Sync + Send {
     async fn create(&self, create_fungible_token: FungibleToken) -> Result<FungibleToken, CommonError>;
     async fn list(&self, params: FungibleTokenQueryParams) -> Result<ResultPaging<FungibleToken>, CommonError>;
     async fn get(&self, token_address: &str) -> Result<FungibleToken, CommonError>;
+    async fn find_by_symbol(&self, symbol: &str) -> Result<Option<FungibleToken>, CommonError>;
+    async fn get_by_symbol(&self, symbol: &str) -> Result<FungibleToken, CommonError>;
+    async fn check_symbol_available(&self, symbol: &str, issuer_address: &Address) -> Result<(), CommonError>;
+    async fn update(&self, token_address: &str, updated_fungible_token: UpdatedFungibleToken) -> Result<FungibleToken, CommonError>;
+    async fn change_owner(&self, change: OwnershipChange) -> Result<FungibleToken, CommonError>;
+    async fn ownership_history(&self, token_address: &str) -> Result<Vec<OwnershipChange>, CommonError>;
     async fn mint(&self, token_address: &str, requester_address: &str, amount: i64) -> Result<FungibleToken, CommonError>;
     async fn burn(&self, token_address: &str, requester_wallet: &Wallet, amount: i64) -> Result<FungibleToken, CommonError>;
 }
@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+
+use crate::domain::error::CommonError;
+use crate::domain::models::health::{HealthReport, NodeInfo};
+
+#[async_trait]
+pub trait HealthService: Sync + Send {
+    async fn readiness(&self) -> HealthReport;
+    async fn info(&self) -> Result<NodeInfo, CommonError>;
+}
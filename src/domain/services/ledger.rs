@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+
+use crate::domain::error::CommonError;
+use crate::domain::models::ledger::LedgerChanges;
+
+#[async_trait]
+pub trait LedgerService: Sync + Send {
+    async fn commit(&self, changes: LedgerChanges) -> Result<(), CommonError>;
+}
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use crate::domain::error::CommonError;
+use crate::domain::models::transaction::{CreateTransaction, Transaction};
+
+// Holds the transactions waiting to be mined: admission, prioritization and expiry
+#[async_trait]
+pub trait MempoolService: Sync + Send {
+    // Admits the transactions in the order given, with the result of each, either all of them or none when all_or_nothing is set
+    async fn submit(&self, transactions: Vec<CreateTransaction>, all_or_nothing: bool) -> Result<Vec<Result<Transaction, CommonError>>, CommonError>;
+    // The pending transactions to mine next, by fee then arrival while keeping the nonce order of each sender
+    async fn select(&self, limit: usize) -> Result<Vec<Transaction>, CommonError>;
+    // Withdraws a pending transaction on behalf of its sender, the transaction is kept as CANCELLED
+    async fn cancel(&self, transaction_hash: &str, requester_address: &str) -> Result<Transaction, CommonError>;
+    async fn expire(&self) -> Result<Vec<Transaction>, CommonError>;
+}
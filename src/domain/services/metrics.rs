@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+
+use crate::domain::error::CommonError;
+
+#[async_trait]
+pub trait MetricsService: Sync + Send {
+    fn observe_http_request(&self, method: &str, route: &str, status: u16, seconds: f64);
+    fn observe_block_production(&self, seconds: f64);
+    fn record_transaction_executed(&self, transaction_type: &str, status: &str);
+    async fn render(&self) -> Result<String, CommonError>;
+}
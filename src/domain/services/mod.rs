@@ -1,5 +1,13 @@
 pub mod block;
+pub mod chain;
+pub mod event_bus;
 pub mod fungible_token;
+pub mod health;
+pub mod ledger;
+pub mod mempool;
+pub mod metrics;
+pub mod schema;
 pub mod service_context;
 pub mod transaction;
+pub mod vesting;
 pub mod wallet;
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+
+use crate::domain::error::CommonError;
+use crate::domain::models::schema::SchemaStatus;
+
+#[async_trait]
+pub trait SchemaService: Sync + Send {
+    async fn check_connection(&self) -> Result<(), CommonError>;
+    async fn status(&self) -> Result<SchemaStatus, CommonError>;
+    // Only run at startup, before the server accepts requests
+    fn migrate(&self) -> Result<SchemaStatus, CommonError>;
+}
@@ -1,16 +1,22 @@
 use async_trait::async_trait;
 
 use crate::domain::error::CommonError;
-use crate::domain::models::transaction::{CreateTransaction, Transaction, UpdateTransaction};
+use crate::domain::models::transaction::{CreateTransaction, Transaction, TransactionReceipt, TransactionSimulation, UpdateTransaction};
 use crate::domain::repositories::repository::ResultPaging;
 use crate::domain::repositories::transaction::TransactionQueryParams;
 
 #[async_trait]
 pub trait TransactionService: Sync + Send {
     async fn create(&self, transaction: CreateTransaction) -> Result<Transaction, CommonError>;
+    // Items already rejected while parsing the batch are reported as they are, in their position
+    async fn create_batch(&self, transactions: Vec<Result<CreateTransaction, CommonError>>, all_or_nothing: bool) -> Result<Vec<Result<Transaction, CommonError>>, CommonError>;
     async fn list(&self, params: TransactionQueryParams) -> Result<ResultPaging<Transaction>, CommonError>;
+    async fn pending_count(&self) -> Result<i64, CommonError>;
+    async fn list_by_block(&self, block_number: i32) -> Result<Vec<Transaction>, CommonError>;
     async fn get(&self, transaction_hash: &str) -> Result<Transaction, CommonError>;
-    async fn delete(&self, transaction_id: i32) -> Result<(), CommonError>;
-    async fn update(&self, transaction_id: i32, update_data: UpdateTransaction) -> Result<Transaction, CommonError>;
-    async fn execute(&self, block_number: i32, transaction: &Transaction) -> Result<(), CommonError>;
+    async fn cancel(&self, transaction_hash: &str, requester_address: &str) -> Result<Transaction, CommonError>;
+    async fn mine_pending(&self, transaction_id: i32, update_data: UpdateTransaction) -> Result<Option<Transaction>, CommonError>;
+    async fn simulate(&self, block_number: i32, transaction: CreateTransaction) -> Result<TransactionSimulation, CommonError>;
+    // None when the transaction left the mempool before its block could apply it
+    async fn execute(&self, block_number: i32, transaction: &Transaction) -> Result<Option<TransactionReceipt>, CommonError>;
 }
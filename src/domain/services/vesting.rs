@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+
+use crate::domain::error::CommonError;
+use crate::domain::models::vesting::{CreateVestingSchedule, VestingSchedule, VestingStatus};
+
+#[async_trait]
+pub trait VestingService: Sync + Send {
+    async fn create(&self, new_schedule: CreateVestingSchedule) -> Result<VestingSchedule, CommonError>;
+    async fn list(&self, beneficiary_address: &str, token_address: Option<&str>) -> Result<Vec<VestingSchedule>, CommonError>;
+    async fn update_claimed(&self, schedule_id: i32, claimed_amount: i64) -> Result<VestingSchedule, CommonError>;
+    async fn status(&self, beneficiary_address: &str, block_number: i32, timestamp: i64) -> Result<Vec<VestingStatus>, CommonError>;
+}
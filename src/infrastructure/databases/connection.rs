@@ -0,0 +1,62 @@
+use diesel::connection::SimpleConnection;
+use diesel::pg::PgConnection;
+use diesel::r2d2::{self, R2D2Connection};
+use diesel::sqlite::SqliteConnection;
+use diesel::Connection;
+
+// A single connection type lets the Diesel repositories run unchanged against every supported database
+#[derive(diesel::MultiConnection)]
+pub enum DbConnection {
+    Postgresql(PgConnection),
+    Sqlite(SqliteConnection),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DbBackend {
+    Postgresql,
+    Sqlite,
+}
+
+pub type DBConn = r2d2::Pool<DbConnectionManager>;
+
+// Establishes connections for the configured backend only, so a connection failure reports the real cause
+pub struct DbConnectionManager {
+    backend: DbBackend,
+    database_url: String,
+}
+
+impl DbConnectionManager {
+    pub fn new(backend: DbBackend, database_url: impl Into<String>) -> Self {
+        DbConnectionManager {
+            backend,
+            database_url: database_url.into(),
+        }
+    }
+}
+
+impl r2d2::ManageConnection for DbConnectionManager {
+    type Connection = DbConnection;
+    type Error = r2d2::Error;
+
+    fn connect(&self) -> Result<Self::Connection, Self::Error> {
+        let mut conn = match self.backend {
+            DbBackend::Postgresql => PgConnection::establish(&self.database_url).map(DbConnection::Postgresql),
+            DbBackend::Sqlite => SqliteConnection::establish(&self.database_url).map(DbConnection::Sqlite),
+        }
+        .map_err(r2d2::Error::ConnectionError)?;
+
+        if let DbConnection::Sqlite(sqlite_conn) = &mut conn {
+            // Wait for concurrent writers instead of failing with "database is locked"
+            sqlite_conn.batch_execute("PRAGMA busy_timeout = 5000; PRAGMA foreign_keys = ON;").map_err(r2d2::Error::QueryError)?;
+        }
+        Ok(conn)
+    }
+
+    fn is_valid(&self, conn: &mut Self::Connection) -> Result<(), Self::Error> {
+        conn.ping().map_err(r2d2::Error::QueryError)
+    }
+
+    fn has_broken(&self, conn: &mut Self::Connection) -> bool {
+        std::thread::panicking() || conn.is_broken()
+    }
+}
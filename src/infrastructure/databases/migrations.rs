@@ -0,0 +1,15 @@
+use std::env;
+
+use diesel_migrations::{embed_migrations, EmbeddedMigrations};
+use dotenv::dotenv;
+
+use crate::domain::constants::RUN_MIGRATIONS;
+
+pub const POSTGRESQL_MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+pub const SQLITE_MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations_sqlite");
+
+// Pending migrations are applied on startup unless RUN_MIGRATIONS is set to false
+pub fn migrations_enabled() -> bool {
+    dotenv().ok();
+    env::var(RUN_MIGRATIONS).map(|value| value.to_lowercase() != "false").unwrap_or(true)
+}
@@ -1 +1,4 @@
+pub mod connection;
+pub mod migrations;
 pub mod postgresql;
+pub mod sqlite;
@@ -0,0 +1,29 @@
+use std::env;
+
+use diesel::r2d2;
+use dotenv::dotenv;
+
+use crate::domain::constants::SQLITE_DB_URI;
+use crate::infrastructure::databases::connection::{DBConn, DbBackend, DbConnectionManager};
+
+pub const DEFAULT_SQLITE_DB_URI: &str = "rustychain.db";
+pub const SQLITE_IN_MEMORY_DB_URI: &str = ":memory:";
+const SQLITE_DB_POOL_SIZE: u32 = 4;
+
+pub fn db_pool() -> DBConn {
+    dotenv().ok();
+    let database_url = env::var(SQLITE_DB_URI).unwrap_or_else(|_| DEFAULT_SQLITE_DB_URI.to_string());
+    db_pool_with_url(&database_url)
+}
+
+pub fn db_pool_with_url(database_url: &str) -> DBConn {
+    // A ":memory:" database only lives as long as its connection, so it must never be shared or recycled
+    let pool_size = if database_url == SQLITE_IN_MEMORY_DB_URI { 1 } else { SQLITE_DB_POOL_SIZE };
+    let manager = DbConnectionManager::new(DbBackend::Sqlite, database_url);
+    r2d2::Pool::builder()
+        .max_size(pool_size)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .build(manager)
+        .expect("Failed to create pool")
+}
@@ -13,6 +13,12 @@ impl DieselRepositoryError {
     }
 }
 
+impl From<RepositoryError> for DieselRepositoryError {
+    fn from(error: RepositoryError) -> DieselRepositoryError {
+        DieselRepositoryError(error)
+    }
+}
+
 impl From<r2d2::Error> for DieselRepositoryError {
     fn from(error: r2d2::Error) -> DieselRepositoryError {
         DieselRepositoryError(RepositoryError { message: error.to_string() })
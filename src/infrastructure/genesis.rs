@@ -0,0 +1,34 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use dotenv::dotenv;
+
+use crate::domain::constants::GENESIS_FILE;
+use crate::domain::models::genesis::Genesis;
+
+pub const DEFAULT_CHAIN_ID: &str = "rustychain";
+
+pub fn load_genesis() -> Genesis {
+    dotenv().ok();
+    match env::var(GENESIS_FILE) {
+        Ok(path) => {
+            let contents = fs::read_to_string(&path).unwrap_or_else(|e| panic!("Failed to read genesis file {}: {}", path, e));
+            parse_genesis(&path, &contents).unwrap_or_else(|e| panic!("Failed to parse genesis file {}: {}", path, e))
+        }
+        // Without a genesis file the chain starts empty
+        Err(_) => Genesis {
+            chain_id: DEFAULT_CHAIN_ID.to_string(),
+            ..Default::default()
+        },
+    }
+}
+
+// The format follows the file extension, JSON unless the file ends with .toml
+pub fn parse_genesis(path: &str, contents: &str) -> Result<Genesis, String> {
+    if Path::new(path).extension().is_some_and(|extension| extension.eq_ignore_ascii_case("toml")) {
+        toml::from_str(contents).map_err(|e| e.to_string())
+    } else {
+        serde_json::from_str(contents).map_err(|e| e.to_string())
+    }
+}
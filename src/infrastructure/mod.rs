@@ -1,6 +1,8 @@
 pub mod databases;
 pub mod error;
+pub mod genesis;
 pub mod models;
 pub mod repositories;
 pub mod schema;
 pub mod services;
+pub mod telemetry;
@@ -1,3 +1,4 @@
+use crate::domain::models::address::Address;
 use crate::domain::models::block::{Block, CreateBlock};
 use crate::infrastructure::schema::blocks;
 use diesel;
@@ -20,7 +21,7 @@ impl From<Block> for BlockDiesel {
             block_hash: t.block_hash,
             parent_hash: t.parent_hash,
             timestamp: t.timestamp,
-            miner_address: t.miner_address,
+            miner_address: t.miner_address.into(),
             transaction_count: t.transaction_count,
         }
     }
@@ -33,7 +34,7 @@ impl Into<Block> for BlockDiesel {
             block_hash: self.block_hash,
             parent_hash: self.parent_hash,
             timestamp: self.timestamp,
-            miner_address: self.miner_address,
+            miner_address: Address::new_unchecked(self.miner_address),
             transaction_count: self.transaction_count,
         }
     }
@@ -42,6 +43,7 @@ impl Into<Block> for BlockDiesel {
 #[derive(Insertable)]
 #[diesel(table_name = blocks)]
 pub struct CreateBlockDiesel {
+    pub block_number: Option<i32>,
     pub block_hash: String,
     pub parent_hash: String,
     pub timestamp: Option<chrono::NaiveDateTime>,
@@ -52,10 +54,11 @@ pub struct CreateBlockDiesel {
 impl From<CreateBlock> for CreateBlockDiesel {
     fn from(t: CreateBlock) -> Self {
         CreateBlockDiesel {
+            block_number: t.block_number,
             block_hash: t.block_hash,
             parent_hash: t.parent_hash,
             timestamp: t.timestamp,
-            miner_address: t.miner_address,
+            miner_address: t.miner_address.into(),
             transaction_count: t.transaction_count,
         }
     }
@@ -64,10 +67,11 @@ impl From<CreateBlock> for CreateBlockDiesel {
 impl Into<CreateBlock> for CreateBlockDiesel {
     fn into(self) -> CreateBlock {
         CreateBlock {
+            block_number: self.block_number,
             block_hash: self.block_hash,
             parent_hash: self.parent_hash,
             timestamp: self.timestamp,
-            miner_address: self.miner_address,
+            miner_address: Address::new_unchecked(self.miner_address),
             transaction_count: self.transaction_count,
         }
     }
@@ -1,5 +1,6 @@
-use crate::domain::models::fungible_token::{FungibleToken, UpdatedFungibleToken};
-use crate::infrastructure::schema::fungible_tokens;
+use crate::domain::models::address::Address;
+use crate::domain::models::fungible_token::{FungibleToken, OwnershipChange, UpdatedFungibleToken};
+use crate::infrastructure::schema::{fungible_tokens, token_ownership_changes};
 
 use diesel;
 use diesel::prelude::*;
@@ -15,19 +16,30 @@ pub struct FungibleTokenDiesel {
     pub total_supply: i64,
     pub block_number: i32,
     pub transaction_hash: String,
+    // JSON array of addresses
+    pub minter_addresses: String,
+    pub max_supply: Option<i64>,
+    pub mintable: bool,
+    pub burnable: bool,
+    pub paused: bool,
 }
 
 impl From<FungibleToken> for FungibleTokenDiesel {
     fn from(t: FungibleToken) -> Self {
         FungibleTokenDiesel {
-            address: t.address,
+            address: t.address.into(),
             symbol: t.symbol,
             name: t.name,
-            owner_address: t.owner_address,
+            owner_address: t.owner_address.into(),
             decimals: t.decimals,
             total_supply: t.total_supply,
             block_number: t.block_number,
             transaction_hash: t.transaction_hash,
+            minter_addresses: serde_json::to_string(&t.minter_addresses).unwrap(),
+            max_supply: t.max_supply,
+            mintable: t.mintable,
+            burnable: t.burnable,
+            paused: t.paused,
         }
     }
 }
@@ -35,14 +47,19 @@ impl From<FungibleToken> for FungibleTokenDiesel {
 impl Into<FungibleToken> for FungibleTokenDiesel {
     fn into(self) -> FungibleToken {
         FungibleToken {
-            address: self.address,
+            address: Address::new_unchecked(self.address),
             symbol: self.symbol,
             name: self.name,
-            owner_address: self.owner_address,
+            owner_address: Address::new_unchecked(self.owner_address),
             decimals: self.decimals,
             total_supply: self.total_supply,
             block_number: self.block_number,
             transaction_hash: self.transaction_hash,
+            minter_addresses: serde_json::from_str(&self.minter_addresses).unwrap_or_default(),
+            max_supply: self.max_supply,
+            mintable: self.mintable,
+            burnable: self.burnable,
+            paused: self.paused,
         }
     }
 }
@@ -51,10 +68,60 @@ impl Into<FungibleToken> for FungibleTokenDiesel {
 #[diesel(table_name = fungible_tokens)]
 pub struct UpdatedFungibleTokenDiesel {
     pub total_supply: Option<i64>,
+    pub minter_addresses: Option<String>,
+    pub paused: Option<bool>,
 }
 
 impl From<UpdatedFungibleToken> for UpdatedFungibleTokenDiesel {
     fn from(u: UpdatedFungibleToken) -> Self {
-        UpdatedFungibleTokenDiesel { total_supply: u.total_supply }
+        UpdatedFungibleTokenDiesel {
+            total_supply: u.total_supply,
+            minter_addresses: u.minter_addresses.map(|minter_addresses| serde_json::to_string(&minter_addresses).unwrap()),
+            paused: u.paused,
+        }
+    }
+}
+
+#[derive(Queryable)]
+pub struct OwnershipChangeDiesel {
+    pub id: i32,
+    pub token_address: String,
+    pub previous_owner_address: String,
+    pub new_owner_address: String,
+    pub block_number: i32,
+    pub transaction_hash: String,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = token_ownership_changes)]
+pub struct CreateOwnershipChangeDiesel {
+    pub token_address: String,
+    pub previous_owner_address: String,
+    pub new_owner_address: String,
+    pub block_number: i32,
+    pub transaction_hash: String,
+}
+
+impl From<OwnershipChange> for CreateOwnershipChangeDiesel {
+    fn from(c: OwnershipChange) -> Self {
+        CreateOwnershipChangeDiesel {
+            token_address: c.token_address.into(),
+            previous_owner_address: c.previous_owner_address.into(),
+            new_owner_address: c.new_owner_address.into(),
+            block_number: c.block_number,
+            transaction_hash: c.transaction_hash,
+        }
+    }
+}
+
+impl Into<OwnershipChange> for OwnershipChangeDiesel {
+    fn into(self) -> OwnershipChange {
+        OwnershipChange {
+            token_address: Address::new_unchecked(self.token_address),
+            previous_owner_address: Address::new_unchecked(self.previous_owner_address),
+            new_owner_address: Address::new_unchecked(self.new_owner_address),
+            block_number: self.block_number,
+            transaction_hash: self.transaction_hash,
+        }
     }
 }
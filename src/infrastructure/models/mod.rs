@@ -2,4 +2,5 @@ pub mod block;
 pub mod fungible_token;
 pub mod service_context;
 pub mod transaction;
+pub mod vesting;
 pub mod wallet;
@@ -1,9 +1,10 @@
+use crate::domain::error::RepositoryError;
+use crate::domain::models::address::Address;
 use crate::domain::models::transaction::{CreateTransaction, Transaction, TransactionStatus, UpdateTransaction};
 use crate::domain::models::transaction_type::TransactionType;
 use crate::infrastructure::schema::transactions;
 use diesel;
 use diesel::prelude::*;
-use serde_json::Value;
 
 #[derive(Queryable)]
 pub struct TransactionDiesel {
@@ -15,9 +16,13 @@ pub struct TransactionDiesel {
     pub transaction_type: String,
     pub value: i64,
     pub timestamp: Option<chrono::NaiveDateTime>,
-    pub data: Option<Value>,
+    pub data: Option<String>,
     pub is_mined: Option<bool>,
     pub status: String,
+    pub idempotency_key: Option<String>,
+    pub fee: i64,
+    pub nonce: i64,
+    pub created_token_address: Option<String>,
 }
 
 // Factory method for creating a new TransactionDiesel from a Transaction
@@ -27,14 +32,18 @@ impl From<Transaction> for TransactionDiesel {
             id: t.id,
             block_number: t.block_number,
             transaction_hash: t.transaction_hash,
-            from_address: t.from_address,
-            to_address: t.to_address,
+            from_address: t.from_address.into(),
+            to_address: t.to_address.into(),
             transaction_type: t.transaction_type.as_str().to_string(),
             value: t.value,
             timestamp: t.timestamp,
-            data: t.data,
+            data: t.data.map(|v| v.to_string()),
             is_mined: t.is_mined,
             status: t.status.to_string(),
+            idempotency_key: t.idempotency_key,
+            fee: t.fee,
+            nonce: t.nonce,
+            created_token_address: t.created_token_address.map(String::from),
         }
     }
 }
@@ -48,26 +57,43 @@ pub struct CreateTransactionDiesel {
     pub transaction_type: String,
     pub value: i64,
     pub timestamp: Option<chrono::NaiveDateTime>,
-    pub data: Option<Value>,
+    pub data: Option<String>,
     pub status: String,
+    pub idempotency_key: Option<String>,
+    pub fee: i64,
+    pub nonce: i64,
+}
+
+// A stored transaction type unknown to this binary is reported as a repository error rather than failing the request
+fn stored_transaction_type(transaction_hash: &str, transaction_type: &str) -> Result<TransactionType, RepositoryError> {
+    transaction_type.parse().map_err(|_| RepositoryError {
+        message: format!("Transaction {} has an unknown type {}", transaction_hash, transaction_type),
+    })
 }
 
 // Factory method for creating a new Transaction from a TransactionDiesel
-impl Into<Transaction> for TransactionDiesel {
-    fn into(self) -> Transaction {
-        Transaction {
-            id: self.id,
-            block_number: self.block_number,
-            transaction_hash: self.transaction_hash,
-            from_address: self.from_address,
-            to_address: self.to_address,
-            transaction_type: TransactionType::from_str(&self.transaction_type),
-            value: self.value,
-            timestamp: self.timestamp,
-            data: self.data,
-            is_mined: self.is_mined,
-            status: self.status.parse::<TransactionStatus>().unwrap_or(TransactionStatus::RAW),
-        }
+impl TryFrom<TransactionDiesel> for Transaction {
+    type Error = RepositoryError;
+
+    fn try_from(t: TransactionDiesel) -> Result<Self, Self::Error> {
+        let transaction_type = stored_transaction_type(&t.transaction_hash, &t.transaction_type)?;
+        Ok(Transaction {
+            id: t.id,
+            block_number: t.block_number,
+            transaction_hash: t.transaction_hash,
+            from_address: Address::new_unchecked(t.from_address),
+            to_address: Address::new_unchecked(t.to_address),
+            transaction_type,
+            value: t.value,
+            timestamp: t.timestamp,
+            data: t.data.and_then(|v| serde_json::from_str(&v).ok()),
+            is_mined: t.is_mined,
+            status: t.status.parse::<TransactionStatus>().unwrap_or(TransactionStatus::RAW),
+            idempotency_key: t.idempotency_key,
+            fee: t.fee,
+            nonce: t.nonce,
+            created_token_address: t.created_token_address.map(Address::new_unchecked),
+        })
     }
 }
 
@@ -75,28 +101,37 @@ impl From<CreateTransaction> for CreateTransactionDiesel {
     fn from(t: CreateTransaction) -> Self {
         CreateTransactionDiesel {
             transaction_hash: t.transaction_hash,
-            from_address: t.from_address,
-            to_address: t.to_address,
+            from_address: t.from_address.into(),
+            to_address: t.to_address.into(),
             transaction_type: t.transaction_type.as_str().to_string(),
             value: t.value,
             timestamp: t.timestamp,
-            data: t.data,
+            data: t.data.map(|v| v.to_string()),
             status: TransactionStatus::RAW.to_string(),
+            idempotency_key: t.idempotency_key,
+            fee: t.fee,
+            nonce: t.nonce.unwrap_or_default(),
         }
     }
 }
 
-impl Into<CreateTransaction> for CreateTransactionDiesel {
-    fn into(self) -> CreateTransaction {
-        CreateTransaction {
-            transaction_hash: self.transaction_hash,
-            from_address: self.from_address,
-            to_address: self.to_address,
-            transaction_type: TransactionType::from_str(&self.transaction_type),
-            value: self.value,
-            timestamp: self.timestamp,
-            data: self.data,
-        }
+impl TryFrom<CreateTransactionDiesel> for CreateTransaction {
+    type Error = RepositoryError;
+
+    fn try_from(t: CreateTransactionDiesel) -> Result<Self, Self::Error> {
+        let transaction_type = stored_transaction_type(&t.transaction_hash, &t.transaction_type)?;
+        Ok(CreateTransaction {
+            transaction_hash: t.transaction_hash,
+            from_address: Address::new_unchecked(t.from_address),
+            to_address: Address::new_unchecked(t.to_address),
+            transaction_type,
+            value: t.value,
+            timestamp: t.timestamp,
+            data: t.data.and_then(|v| serde_json::from_str(&v).ok()),
+            idempotency_key: t.idempotency_key,
+            fee: t.fee,
+            nonce: Some(t.nonce),
+        })
     }
 }
 
@@ -106,6 +141,7 @@ pub struct UpdateTransactionDiesel {
     pub block_number: Option<i32>,
     pub is_mined: Option<bool>,
     pub status: Option<String>,
+    pub created_token_address: Option<String>,
 }
 
 impl From<UpdateTransaction> for UpdateTransactionDiesel {
@@ -114,6 +150,7 @@ impl From<UpdateTransaction> for UpdateTransactionDiesel {
             block_number: update.block_number,
             is_mined: update.is_mined,
             status: Some(update.status.unwrap().to_string()),
+            created_token_address: update.created_token_address.map(String::from),
         }
     }
 }
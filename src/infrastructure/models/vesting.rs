@@ -0,0 +1,73 @@
+use crate::domain::models::address::Address;
+use crate::domain::models::vesting::{CreateVestingSchedule, VestingSchedule, VestingUnit};
+use crate::infrastructure::schema::vesting_schedules;
+
+use diesel;
+use diesel::prelude::*;
+
+#[derive(Queryable)]
+pub struct VestingScheduleDiesel {
+    pub id: i32,
+    pub token_address: String,
+    pub grantor_address: String,
+    pub beneficiary_address: String,
+    pub unit: String,
+    pub start: i64,
+    pub cliff: i64,
+    pub duration: i64,
+    pub total_amount: i64,
+    pub claimed_amount: i64,
+    pub block_number: i32,
+    pub transaction_hash: String,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = vesting_schedules)]
+pub struct CreateVestingScheduleDiesel {
+    pub token_address: String,
+    pub grantor_address: String,
+    pub beneficiary_address: String,
+    pub unit: String,
+    pub start: i64,
+    pub cliff: i64,
+    pub duration: i64,
+    pub total_amount: i64,
+    pub block_number: i32,
+    pub transaction_hash: String,
+}
+
+impl From<CreateVestingSchedule> for CreateVestingScheduleDiesel {
+    fn from(s: CreateVestingSchedule) -> Self {
+        CreateVestingScheduleDiesel {
+            token_address: s.token_address.into(),
+            grantor_address: s.grantor_address.into(),
+            beneficiary_address: s.beneficiary_address.into(),
+            unit: s.unit.to_string(),
+            start: s.start,
+            cliff: s.cliff,
+            duration: s.duration,
+            total_amount: s.total_amount,
+            block_number: s.block_number,
+            transaction_hash: s.transaction_hash,
+        }
+    }
+}
+
+impl Into<VestingSchedule> for VestingScheduleDiesel {
+    fn into(self) -> VestingSchedule {
+        VestingSchedule {
+            id: self.id,
+            token_address: Address::new_unchecked(self.token_address),
+            grantor_address: Address::new_unchecked(self.grantor_address),
+            beneficiary_address: Address::new_unchecked(self.beneficiary_address),
+            unit: self.unit.parse::<VestingUnit>().unwrap_or(VestingUnit::BLOCK),
+            start: self.start,
+            cliff: self.cliff,
+            duration: self.duration,
+            total_amount: self.total_amount,
+            claimed_amount: self.claimed_amount,
+            block_number: self.block_number,
+            transaction_hash: self.transaction_hash,
+        }
+    }
+}
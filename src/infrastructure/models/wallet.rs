@@ -1,3 +1,4 @@
+use crate::domain::models::address::Address;
 use crate::domain::models::wallet::Wallet;
 use crate::infrastructure::schema::wallets;
 use diesel;
@@ -11,16 +12,18 @@ pub struct WalletDiesel {
     pub balance: i64,
     pub block_number: i32,
     pub transaction_hash: String,
+    pub frozen: bool,
 }
 
 impl From<Wallet> for WalletDiesel {
     fn from(t: Wallet) -> Self {
         WalletDiesel {
-            address: t.address,
-            token_address: t.token_address,
+            address: t.address.into(),
+            token_address: t.token_address.into(),
             balance: t.balance,
             block_number: t.block_number,
             transaction_hash: t.transaction_hash,
+            frozen: t.frozen,
         }
     }
 }
@@ -28,11 +31,12 @@ impl From<Wallet> for WalletDiesel {
 impl Into<Wallet> for WalletDiesel {
     fn into(self) -> Wallet {
         Wallet {
-            address: self.address,
-            token_address: self.token_address,
+            address: Address::new_unchecked(self.address),
+            token_address: Address::new_unchecked(self.token_address),
             balance: self.balance,
             block_number: self.block_number,
             transaction_hash: self.transaction_hash,
+            frozen: self.frozen,
         }
     }
 }
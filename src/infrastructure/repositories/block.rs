@@ -6,9 +6,10 @@ use std::sync::Arc;
 use crate::domain::models::block::{Block, CreateBlock};
 use crate::domain::repositories::block::{BlockQueryParams, BlockRepository};
 use crate::domain::repositories::repository::{QueryParams, RepositoryResult, ResultPaging};
-use crate::infrastructure::databases::postgresql::DBConn;
+use crate::infrastructure::databases::connection::DBConn;
 use crate::infrastructure::error::DieselRepositoryError;
 use crate::infrastructure::models::block::{BlockDiesel, CreateBlockDiesel};
+use tracing::instrument;
 
 pub struct BlockDieselRepository {
     pub pool: Arc<DBConn>,
@@ -22,6 +23,7 @@ impl BlockDieselRepository {
 
 #[async_trait]
 impl BlockRepository for BlockDieselRepository {
+    #[instrument(name = "block_repository.create", level = "debug", skip_all)]
     async fn create(&self, new_block: &CreateBlock) -> RepositoryResult<Block> {
         use crate::infrastructure::schema::blocks::dsl::blocks;
         let new_block_diesel: CreateBlockDiesel = CreateBlockDiesel::from(new_block.clone());
@@ -32,10 +34,11 @@ impl BlockRepository for BlockDieselRepository {
         Ok(result.into())
     }
 
+    #[instrument(name = "block_repository.list", level = "debug", skip_all, fields(params = ?params))]
     async fn list(&self, params: BlockQueryParams) -> RepositoryResult<ResultPaging<Block>> {
-        use crate::infrastructure::schema::blocks::dsl::{blocks, timestamp};
+        use crate::infrastructure::schema::blocks::dsl::{block_number, blocks};
         let pool = self.pool.clone();
-        let builder = blocks.limit(params.limit()).offset(params.offset()).order_by(timestamp.desc()); // Add order_by clause
+        let builder = blocks.limit(params.limit()).offset(params.offset()).order_by(block_number.desc()); // Latest block first
         let result = run(move || {
             let mut conn = pool.get().unwrap();
             builder.load::<BlockDiesel>(&mut conn)
@@ -48,6 +51,21 @@ impl BlockRepository for BlockDieselRepository {
         })
     }
 
+    #[instrument(name = "block_repository.list_after", level = "debug", skip_all, fields(block_number = block_nmb, limit))]
+    async fn list_after(&self, block_nmb: i32, limit: i64) -> RepositoryResult<Vec<Block>> {
+        use crate::infrastructure::schema::blocks::dsl::{block_number, blocks};
+        let pool = self.pool.clone();
+        let builder = blocks.filter(block_number.gt(block_nmb)).order_by(block_number.asc()).limit(limit); // Oldest block first
+        let result = run(move || {
+            let mut conn = pool.get().unwrap();
+            builder.load::<BlockDiesel>(&mut conn)
+        })
+        .await
+        .map_err(|v| DieselRepositoryError::from(v).into_inner())?;
+        Ok(result.into_iter().map(|v| v.into()).collect())
+    }
+
+    #[instrument(name = "block_repository.get", level = "debug", skip_all, fields(block_number = block_nmb))]
     async fn get(&self, block_nmb: i32) -> RepositoryResult<Block> {
         use crate::infrastructure::schema::blocks::dsl::{block_number, blocks};
         let mut conn = self.pool.get().unwrap();
@@ -57,6 +75,7 @@ impl BlockRepository for BlockDieselRepository {
             .map(|v| -> Block { v.into() })
     }
 
+    #[instrument(name = "block_repository.delete", level = "debug", skip_all, fields(block_number = block_nmb))]
     async fn delete(&self, block_nmb: i32) -> RepositoryResult<()> {
         use crate::infrastructure::schema::blocks::dsl::{block_number, blocks};
         let mut conn = self.pool.get().unwrap();
@@ -3,12 +3,21 @@ use async_trait::async_trait;
 use diesel::prelude::*;
 use std::sync::Arc;
 
-use crate::domain::models::fungible_token::{FungibleToken, UpdatedFungibleToken};
+use crate::domain::models::fungible_token::{FungibleToken, OwnershipChange, UpdatedFungibleToken};
 use crate::domain::repositories::fungible_token::{FungibleTokenQueryParams, FungibleTokenRepository};
 use crate::domain::repositories::repository::{QueryParams, RepositoryResult, ResultPaging};
-use crate::infrastructure::databases::postgresql::DBConn;
+use crate::infrastructure::databases::connection::DBConn;
 use crate::infrastructure::error::DieselRepositoryError;
-use crate::infrastructure::models::fungible_token::{FungibleTokenDiesel, UpdatedFungibleTokenDiesel};
+use crate::infrastructure::models::fungible_token::{CreateOwnershipChangeDiesel, FungibleTokenDiesel, OwnershipChangeDiesel, UpdatedFungibleTokenDiesel};
+use tracing::instrument;
+
+diesel::define_sql_function!(fn lower(x: diesel::sql_types::Text) -> diesel::sql_types::Text);
+
+// Escapes the LIKE wildcards of a user supplied prefix
+fn like_prefix(prefix: &str) -> String {
+    let escaped = prefix.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+    format!("{}%", escaped.to_lowercase())
+}
 
 pub struct FungibleTokenDieselRepository {
     pub pool: Arc<DBConn>,
@@ -22,6 +31,7 @@ impl FungibleTokenDieselRepository {
 
 #[async_trait]
 impl FungibleTokenRepository for FungibleTokenDieselRepository {
+    #[instrument(name = "fungible_token_repository.create", level = "debug", skip_all, fields(token_address = %new_fungible_token.address))]
     async fn create(&self, new_fungible_token: &FungibleToken) -> RepositoryResult<FungibleToken> {
         use crate::infrastructure::schema::fungible_tokens::dsl::fungible_tokens;
         let new_fungible_token_diesel: FungibleTokenDiesel = FungibleTokenDiesel::from(new_fungible_token.clone());
@@ -32,22 +42,32 @@ impl FungibleTokenRepository for FungibleTokenDieselRepository {
         Ok(result.into())
     }
 
+    #[instrument(name = "fungible_token_repository.list", level = "debug", skip_all, fields(params = ?params))]
     async fn list(&self, params: FungibleTokenQueryParams) -> RepositoryResult<ResultPaging<FungibleToken>> {
-        use crate::infrastructure::schema::fungible_tokens::dsl::fungible_tokens;
+        use crate::infrastructure::schema::fungible_tokens::dsl::{fungible_tokens, name};
         let pool = self.pool.clone();
-        let builder = fungible_tokens.limit(params.limit()).offset(params.offset());
-        let result = run(move || {
+        let mut builder = fungible_tokens.limit(params.limit()).offset(params.offset()).into_boxed();
+        // The total counts every matching token, not only the returned page
+        let mut count_builder = fungible_tokens.into_boxed();
+        if let Some(name_prefix) = &params.name_prefix {
+            builder = builder.filter(lower(name).like(like_prefix(name_prefix)).escape('\\'));
+            count_builder = count_builder.filter(lower(name).like(like_prefix(name_prefix)).escape('\\'));
+        }
+        let (result, total) = run(move || {
             let mut conn = pool.get().unwrap();
-            builder.load::<FungibleTokenDiesel>(&mut conn)
+            let result = builder.load::<FungibleTokenDiesel>(&mut conn)?;
+            let total = count_builder.count().get_result::<i64>(&mut conn)?;
+            Ok::<_, diesel::result::Error>((result, total))
         })
         .await
         .map_err(|v| DieselRepositoryError::from(v).into_inner())?;
         Ok(ResultPaging {
-            total: result.len() as i64,
+            total,
             items: result.into_iter().map(|v| v.into()).collect(),
         })
     }
 
+    #[instrument(name = "fungible_token_repository.get", level = "debug", skip_all, fields(token_address = %token_address))]
     async fn get(&self, token_address: &str) -> RepositoryResult<FungibleToken> {
         use crate::infrastructure::schema::fungible_tokens::dsl::{address, fungible_tokens};
         let mut conn = self.pool.get().unwrap();
@@ -59,6 +79,18 @@ impl FungibleTokenRepository for FungibleTokenDieselRepository {
             .map(|v| -> FungibleToken { v.into() })
     }
 
+    #[instrument(name = "fungible_token_repository.find_by_symbol", level = "debug", skip_all, fields(symbol = %requested_symbol))]
+    async fn find_by_symbol(&self, requested_symbol: &str) -> RepositoryResult<Option<FungibleToken>> {
+        use crate::infrastructure::schema::fungible_tokens::dsl::{fungible_tokens, symbol};
+        let mut conn = self.pool.get().unwrap();
+        let requested_symbol = requested_symbol.to_lowercase();
+        run(move || fungible_tokens.filter(lower(symbol).eq(requested_symbol)).first::<FungibleTokenDiesel>(&mut conn).optional())
+            .await
+            .map_err(|v| DieselRepositoryError::from(v).into_inner())
+            .map(|v| v.map(|v| -> FungibleToken { v.into() }))
+    }
+
+    #[instrument(name = "fungible_token_repository.update", level = "debug", skip_all, fields(token_address = %token_address))]
     async fn update(&self, token_address: &str, updated_token_data: UpdatedFungibleToken) -> RepositoryResult<FungibleToken> {
         use crate::infrastructure::schema::fungible_tokens::dsl::{address, fungible_tokens};
         let update_token_diesel = UpdatedFungibleTokenDiesel::from(updated_token_data);
@@ -74,4 +106,42 @@ impl FungibleTokenRepository for FungibleTokenDieselRepository {
         .map_err(|v| DieselRepositoryError::from(v).into_inner())?;
         Ok(updated_token.into())
     }
+
+    #[instrument(name = "fungible_token_repository.change_owner", level = "debug", skip_all, fields(token_address = %change.token_address))]
+    async fn change_owner(&self, change: &OwnershipChange) -> RepositoryResult<FungibleToken> {
+        use crate::infrastructure::schema::fungible_tokens::dsl::{address, fungible_tokens, owner_address};
+        use crate::infrastructure::schema::token_ownership_changes::dsl::token_ownership_changes;
+        let new_change_diesel = CreateOwnershipChangeDiesel::from(change.clone());
+        let mut conn = self.pool.get().unwrap();
+        // A single database transaction, so the owner never changes without its history entry
+        let updated_token = run(move || {
+            conn.transaction(|conn| {
+                let updated_token = diesel::update(fungible_tokens.filter(address.eq(&new_change_diesel.token_address)))
+                    .set(owner_address.eq(&new_change_diesel.new_owner_address))
+                    .get_result::<FungibleTokenDiesel>(conn)?;
+                diesel::insert_into(token_ownership_changes).values(&new_change_diesel).execute(conn)?;
+                Ok::<FungibleTokenDiesel, diesel::result::Error>(updated_token)
+            })
+        })
+        .await
+        .map_err(|v| DieselRepositoryError::from(v).into_inner())?;
+        Ok(updated_token.into())
+    }
+
+    #[instrument(name = "fungible_token_repository.list_ownership_changes", level = "debug", skip_all, fields(token_address = %token_address_val))]
+    async fn list_ownership_changes(&self, token_address_val: &str) -> RepositoryResult<Vec<OwnershipChange>> {
+        use crate::infrastructure::schema::token_ownership_changes::dsl::{id, token_address, token_ownership_changes};
+        let pool = self.pool.clone();
+        let requested_token_address = token_address_val.to_string();
+        let result = run(move || {
+            let mut conn = pool.get().unwrap();
+            token_ownership_changes
+                .filter(token_address.eq(requested_token_address))
+                .order_by(id.asc())
+                .load::<OwnershipChangeDiesel>(&mut conn)
+        })
+        .await
+        .map_err(|v| DieselRepositoryError::from(v).into_inner())?;
+        Ok(result.into_iter().map(|v| v.into()).collect())
+    }
 }
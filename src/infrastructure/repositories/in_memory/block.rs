@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+use crate::domain::models::block::{Block, CreateBlock};
+use crate::domain::repositories::block::{BlockQueryParams, BlockRepository};
+use crate::domain::repositories::repository::{QueryParams, RepositoryResult, ResultPaging};
+use crate::infrastructure::repositories::in_memory::{already_exists, not_found};
+use tracing::instrument;
+
+#[derive(Default)]
+pub struct BlockInMemoryRepository {
+    blocks: RwLock<BTreeMap<i32, Block>>,
+}
+
+impl BlockInMemoryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BlockRepository for BlockInMemoryRepository {
+    #[instrument(name = "block_repository.create", level = "debug", skip_all)]
+    async fn create(&self, new_block: &CreateBlock) -> RepositoryResult<Block> {
+        let mut blocks = self.blocks.write().unwrap();
+        let block_number = new_block.block_number.unwrap_or_else(|| blocks.keys().next_back().map(|last| last + 1).unwrap_or(1));
+        if blocks.contains_key(&block_number) {
+            return Err(already_exists(&block_number.to_string()));
+        }
+
+        let block = Block {
+            block_number,
+            block_hash: new_block.block_hash.clone(),
+            parent_hash: new_block.parent_hash.clone(),
+            timestamp: new_block.timestamp,
+            miner_address: new_block.miner_address.clone(),
+            transaction_count: new_block.transaction_count,
+        };
+        blocks.insert(block_number, block.clone());
+        Ok(block)
+    }
+
+    #[instrument(name = "block_repository.list", level = "debug", skip_all, fields(params = ?params))]
+    async fn list(&self, params: BlockQueryParams) -> RepositoryResult<ResultPaging<Block>> {
+        let blocks = self.blocks.read().unwrap();
+        let items: Vec<Block> = blocks.values().rev().skip(params.offset() as usize).take(params.limit() as usize).cloned().collect();
+        Ok(ResultPaging { total: items.len() as i64, items })
+    }
+
+    #[instrument(name = "block_repository.list_after", level = "debug", skip_all, fields(block_number, limit))]
+    async fn list_after(&self, block_number: i32, limit: i64) -> RepositoryResult<Vec<Block>> {
+        let blocks = self.blocks.read().unwrap();
+        Ok(blocks.range(block_number.saturating_add(1)..).map(|(_, block)| block.clone()).take(limit as usize).collect())
+    }
+
+    #[instrument(name = "block_repository.get", level = "debug", skip_all, fields(block_number))]
+    async fn get(&self, block_number: i32) -> RepositoryResult<Block> {
+        self.blocks.read().unwrap().get(&block_number).cloned().ok_or_else(not_found)
+    }
+
+    #[instrument(name = "block_repository.delete", level = "debug", skip_all, fields(block_number))]
+    async fn delete(&self, block_number: i32) -> RepositoryResult<()> {
+        self.blocks.write().unwrap().remove(&block_number);
+        Ok(())
+    }
+}
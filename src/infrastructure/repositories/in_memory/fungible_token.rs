@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use std::sync::RwLock;
+
+use crate::domain::models::fungible_token::{FungibleToken, OwnershipChange, UpdatedFungibleToken};
+use crate::domain::repositories::fungible_token::{FungibleTokenQueryParams, FungibleTokenRepository};
+use crate::domain::repositories::repository::{QueryParams, RepositoryResult, ResultPaging};
+use crate::infrastructure::repositories::in_memory::{already_exists, not_found};
+use tracing::instrument;
+
+#[derive(Default)]
+pub struct FungibleTokenInMemoryRepository {
+    // Kept in insertion order, like an unordered table scan
+    fungible_tokens: RwLock<Vec<FungibleToken>>,
+    ownership_changes: RwLock<Vec<OwnershipChange>>,
+}
+
+impl FungibleTokenInMemoryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl FungibleTokenRepository for FungibleTokenInMemoryRepository {
+    #[instrument(name = "fungible_token_repository.create", level = "debug", skip_all, fields(token_address = %new_token.address))]
+    async fn create(&self, new_token: &FungibleToken) -> RepositoryResult<FungibleToken> {
+        let mut fungible_tokens = self.fungible_tokens.write().unwrap();
+        if fungible_tokens.iter().any(|t| t.address == new_token.address) {
+            return Err(already_exists(&new_token.address));
+        }
+        fungible_tokens.push(new_token.clone());
+        Ok(new_token.clone())
+    }
+
+    #[instrument(name = "fungible_token_repository.list", level = "debug", skip_all, fields(params = ?params))]
+    async fn list(&self, params: FungibleTokenQueryParams) -> RepositoryResult<ResultPaging<FungibleToken>> {
+        let fungible_tokens = self.fungible_tokens.read().unwrap();
+        let name_prefix = params.name_prefix.as_ref().map(|name_prefix| name_prefix.to_lowercase());
+        let matching: Vec<&FungibleToken> = fungible_tokens
+            .iter()
+            .filter(|t| name_prefix.as_ref().is_none_or(|name_prefix| t.name.to_lowercase().starts_with(name_prefix)))
+            .collect();
+        let items: Vec<FungibleToken> = matching.iter().skip(params.offset() as usize).take(params.limit() as usize).map(|t| (*t).clone()).collect();
+        Ok(ResultPaging { total: matching.len() as i64, items })
+    }
+
+    #[instrument(name = "fungible_token_repository.get", level = "debug", skip_all, fields(token_address = %token_address))]
+    async fn get(&self, token_address: &str) -> RepositoryResult<FungibleToken> {
+        let fungible_tokens = self.fungible_tokens.read().unwrap();
+        fungible_tokens.iter().find(|t| t.address == token_address).cloned().ok_or_else(not_found)
+    }
+
+    #[instrument(name = "fungible_token_repository.find_by_symbol", level = "debug", skip_all, fields(symbol = %symbol))]
+    async fn find_by_symbol(&self, symbol: &str) -> RepositoryResult<Option<FungibleToken>> {
+        let fungible_tokens = self.fungible_tokens.read().unwrap();
+        Ok(fungible_tokens.iter().find(|t| t.symbol.eq_ignore_ascii_case(symbol)).cloned())
+    }
+
+    #[instrument(name = "fungible_token_repository.update", level = "debug", skip_all, fields(token_address = %token_address))]
+    async fn update(&self, token_address: &str, updated_token: UpdatedFungibleToken) -> RepositoryResult<FungibleToken> {
+        let mut fungible_tokens = self.fungible_tokens.write().unwrap();
+        let token = fungible_tokens.iter_mut().find(|t| t.address == token_address).ok_or_else(not_found)?;
+        if let Some(total_supply) = updated_token.total_supply {
+            token.total_supply = total_supply;
+        }
+        if let Some(minter_addresses) = updated_token.minter_addresses {
+            token.minter_addresses = minter_addresses;
+        }
+        if let Some(paused) = updated_token.paused {
+            token.paused = paused;
+        }
+        Ok(token.clone())
+    }
+
+    #[instrument(name = "fungible_token_repository.change_owner", level = "debug", skip_all, fields(token_address = %change.token_address))]
+    async fn change_owner(&self, change: &OwnershipChange) -> RepositoryResult<FungibleToken> {
+        let mut fungible_tokens = self.fungible_tokens.write().unwrap();
+        let token = fungible_tokens.iter_mut().find(|t| t.address == change.token_address).ok_or_else(not_found)?;
+        token.owner_address = change.new_owner_address.clone();
+        self.ownership_changes.write().unwrap().push(change.clone());
+        Ok(token.clone())
+    }
+
+    #[instrument(name = "fungible_token_repository.list_ownership_changes", level = "debug", skip_all, fields(token_address = %token_address))]
+    async fn list_ownership_changes(&self, token_address: &str) -> RepositoryResult<Vec<OwnershipChange>> {
+        let ownership_changes = self.ownership_changes.read().unwrap();
+        Ok(ownership_changes.iter().filter(|c| c.token_address == token_address).cloned().collect())
+    }
+}
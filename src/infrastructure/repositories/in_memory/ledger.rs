@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::domain::models::ledger::{no_longer_pending, LedgerChanges};
+use crate::domain::repositories::block::BlockRepository;
+use crate::domain::repositories::fungible_token::FungibleTokenRepository;
+use crate::domain::repositories::ledger::LedgerRepository;
+use crate::domain::repositories::repository::RepositoryResult;
+use crate::domain::repositories::transaction::TransactionRepository;
+use crate::domain::repositories::vesting::VestingRepository;
+use crate::domain::repositories::wallet::WalletRepository;
+use crate::infrastructure::repositories::in_memory::already_exists;
+use crate::infrastructure::repositories::in_memory::block::BlockInMemoryRepository;
+use crate::infrastructure::repositories::in_memory::fungible_token::FungibleTokenInMemoryRepository;
+use crate::infrastructure::repositories::in_memory::transaction::TransactionInMemoryRepository;
+use crate::infrastructure::repositories::in_memory::vesting::VestingInMemoryRepository;
+use crate::infrastructure::repositories::in_memory::wallet::WalletInMemoryRepository;
+use tracing::instrument;
+
+// Writes through the other in-memory repositories, which cannot roll back
+pub struct LedgerInMemoryRepository {
+    blocks: Arc<BlockInMemoryRepository>,
+    transactions: Arc<TransactionInMemoryRepository>,
+    fungible_tokens: Arc<FungibleTokenInMemoryRepository>,
+    vesting_schedules: Arc<VestingInMemoryRepository>,
+    wallets: Arc<WalletInMemoryRepository>,
+    // Commits run one at a time, so nothing changes between the checks and the writes
+    commit_lock: Mutex<()>,
+}
+
+impl LedgerInMemoryRepository {
+    pub fn new(
+        blocks: Arc<BlockInMemoryRepository>,
+        transactions: Arc<TransactionInMemoryRepository>,
+        fungible_tokens: Arc<FungibleTokenInMemoryRepository>,
+        vesting_schedules: Arc<VestingInMemoryRepository>,
+        wallets: Arc<WalletInMemoryRepository>,
+    ) -> Self {
+        LedgerInMemoryRepository {
+            blocks,
+            transactions,
+            fungible_tokens,
+            vesting_schedules,
+            wallets,
+            commit_lock: Mutex::new(()),
+        }
+    }
+}
+
+#[async_trait]
+impl LedgerRepository for LedgerInMemoryRepository {
+    #[instrument(name = "ledger_repository.commit", level = "debug", skip_all)]
+    async fn commit(&self, changes: &LedgerChanges) -> RepositoryResult<()> {
+        let _commit_guard = self.commit_lock.lock().await;
+
+        // Step-1 : refuse the changes before any write, the inserts of existing records are the only writes that can fail
+        // since the updated records were read from these repositories and none of them is ever deleted
+        if let Some(block_number) = changes.block.as_ref().and_then(|block| block.block_number) {
+            if self.blocks.get(block_number).await.is_ok() {
+                return Err(already_exists(&block_number.to_string()));
+            }
+        }
+        for created_token in &changes.created_tokens {
+            if self.fungible_tokens.get(&created_token.address).await.is_ok() {
+                return Err(already_exists(&created_token.address));
+            }
+        }
+
+        // Step-2 : apply every change, claiming the transaction first so one no longer pending is refused before any other write
+        if let Some((transaction_id, update_transaction)) = &changes.transaction {
+            self.transactions
+                .mine_pending(*transaction_id, update_transaction.clone())
+                .await?
+                .ok_or_else(|| no_longer_pending(*transaction_id))?;
+        }
+        for created_token in &changes.created_tokens {
+            self.fungible_tokens.create(created_token).await?;
+        }
+        for (token_address, updated_token) in &changes.updated_tokens {
+            self.fungible_tokens.update(token_address, updated_token.clone()).await?;
+        }
+        for ownership_change in &changes.ownership_changes {
+            self.fungible_tokens.change_owner(ownership_change).await?;
+        }
+        for created_vesting_schedule in &changes.created_vesting_schedules {
+            self.vesting_schedules.create(created_vesting_schedule).await?;
+        }
+        for (schedule_id, claimed_amount) in &changes.vesting_claims {
+            self.vesting_schedules.update_claimed(*schedule_id, *claimed_amount).await?;
+        }
+        self.wallets.create_or_update_many(&changes.wallets).await?;
+        if let Some(block) = &changes.block {
+            self.blocks.create(block).await?;
+        }
+        Ok(())
+    }
+}
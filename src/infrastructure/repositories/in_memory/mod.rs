@@ -0,0 +1,20 @@
+pub mod block;
+pub mod fungible_token;
+pub mod ledger;
+pub mod transaction;
+pub mod vesting;
+pub mod wallet;
+
+use crate::domain::error::RepositoryError;
+
+pub fn not_found() -> RepositoryError {
+    RepositoryError {
+        message: String::from("Record not found"),
+    }
+}
+
+pub fn already_exists(key: &str) -> RepositoryError {
+    RepositoryError {
+        message: format!("Record {} already exists", key),
+    }
+}
@@ -0,0 +1,205 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+use crate::domain::models::transaction::{CreateTransaction, Transaction, TransactionStatus, UpdateTransaction};
+use crate::domain::repositories::repository::{QueryParams, RepositoryResult, ResultPaging};
+use crate::domain::repositories::transaction::{TransactionQueryParams, TransactionRepository};
+use crate::infrastructure::repositories::in_memory::{already_exists, not_found};
+use tracing::instrument;
+
+#[derive(Default)]
+pub struct TransactionInMemoryRepository {
+    transactions: RwLock<BTreeMap<i32, Transaction>>,
+}
+
+impl TransactionInMemoryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+fn insert_transaction(transactions: &mut BTreeMap<i32, Transaction>, new_transaction: &CreateTransaction) -> RepositoryResult<Transaction> {
+    if transactions.values().any(|t| t.transaction_hash == new_transaction.transaction_hash) {
+        return Err(already_exists(&new_transaction.transaction_hash));
+    }
+    if let Some(idempotency_key) = &new_transaction.idempotency_key {
+        if transactions
+            .values()
+            .any(|t| t.from_address == new_transaction.from_address && t.idempotency_key.as_ref() == Some(idempotency_key))
+        {
+            return Err(already_exists(idempotency_key));
+        }
+    }
+    // Mirrors the unique index of the pending nonces of a sender
+    let nonce = new_transaction.nonce.unwrap_or_default();
+    if transactions.values().any(|t| t.is_pending() && t.from_address == new_transaction.from_address && t.nonce == nonce) {
+        return Err(already_exists(&format!("{}:{}", new_transaction.from_address, nonce)));
+    }
+
+    let id = transactions.keys().next_back().map(|last| last + 1).unwrap_or(1);
+    let transaction = Transaction {
+        id,
+        block_number: None,
+        transaction_hash: new_transaction.transaction_hash.clone(),
+        from_address: new_transaction.from_address.clone(),
+        to_address: new_transaction.to_address.clone(),
+        transaction_type: new_transaction.transaction_type.clone(),
+        value: new_transaction.value,
+        timestamp: new_transaction.timestamp,
+        data: new_transaction.data.clone(),
+        is_mined: Some(false),
+        status: TransactionStatus::RAW,
+        idempotency_key: new_transaction.idempotency_key.clone(),
+        fee: new_transaction.fee,
+        nonce,
+        created_token_address: None,
+    };
+    transactions.insert(id, transaction.clone());
+    Ok(transaction)
+}
+
+#[async_trait]
+impl TransactionRepository for TransactionInMemoryRepository {
+    #[instrument(name = "transaction_repository.create", level = "debug", skip_all, fields(transaction_hash = %new_transaction.transaction_hash))]
+    async fn create(&self, new_transaction: &CreateTransaction) -> RepositoryResult<Transaction> {
+        let mut transactions = self.transactions.write().unwrap();
+        insert_transaction(&mut transactions, new_transaction)
+    }
+
+    #[instrument(name = "transaction_repository.create_many", level = "debug", skip_all, fields(count = new_transactions.len()))]
+    async fn create_many(&self, new_transactions: &[CreateTransaction], replaced_transaction_ids: &[i32]) -> RepositoryResult<Vec<Transaction>> {
+        let mut transactions = self.transactions.write().unwrap();
+        // Stage the changes on a copy, so a failing one leaves the stored transactions untouched
+        let mut staged_transactions = transactions.clone();
+        for replaced_transaction_id in replaced_transaction_ids {
+            let replaced_transaction = staged_transactions.get_mut(replaced_transaction_id).filter(|t| t.is_pending()).ok_or_else(not_found)?;
+            replaced_transaction.status = TransactionStatus::REPLACED;
+        }
+        let created = new_transactions
+            .iter()
+            .map(|new_transaction| insert_transaction(&mut staged_transactions, new_transaction))
+            .collect::<RepositoryResult<Vec<Transaction>>>()?;
+        *transactions = staged_transactions;
+        Ok(created)
+    }
+
+    #[instrument(name = "transaction_repository.list", level = "debug", skip_all, fields(params = ?params))]
+    async fn list(&self, params: TransactionQueryParams) -> RepositoryResult<ResultPaging<Transaction>> {
+        let transactions = self.transactions.read().unwrap();
+        let is_mined = params.is_mined.unwrap_or(false); // Default to false
+        let items: Vec<Transaction> = transactions
+            .values()
+            .filter(|t| t.is_mined == Some(is_mined))
+            .skip(params.offset() as usize)
+            .take(params.limit() as usize)
+            .cloned()
+            .collect();
+        Ok(ResultPaging { total: items.len() as i64, items })
+    }
+
+    #[instrument(name = "transaction_repository.list_pending", level = "debug", skip_all)]
+    async fn list_pending(&self) -> RepositoryResult<Vec<Transaction>> {
+        let transactions = self.transactions.read().unwrap();
+        Ok(transactions.values().filter(|t| t.is_pending()).cloned().collect())
+    }
+
+    #[instrument(name = "transaction_repository.count_pending", level = "debug", skip_all, fields(from_address = ?from_address))]
+    async fn count_pending(&self, from_address: Option<&str>) -> RepositoryResult<i64> {
+        let transactions = self.transactions.read().unwrap();
+        Ok(transactions
+            .values()
+            .filter(|t| t.is_pending())
+            .filter(|t| from_address.is_none_or(|from_address| t.from_address == from_address))
+            .count() as i64)
+    }
+
+    #[instrument(name = "transaction_repository.max_nonce", level = "debug", skip_all, fields(from_address = %from_address))]
+    async fn max_nonce(&self, from_address: &str) -> RepositoryResult<Option<i64>> {
+        let transactions = self.transactions.read().unwrap();
+        Ok(transactions
+            .values()
+            .filter(|t| t.from_address == from_address)
+            .filter(|t| ![TransactionStatus::EXPIRED, TransactionStatus::REPLACED, TransactionStatus::CANCELLED].contains(&t.status))
+            .map(|t| t.nonce)
+            .max())
+    }
+
+    #[instrument(name = "transaction_repository.list_by_nonce", level = "debug", skip_all, fields(from_address = %from_address, nonce))]
+    async fn list_by_nonce(&self, from_address: &str, nonce: i64) -> RepositoryResult<Vec<Transaction>> {
+        let transactions = self.transactions.read().unwrap();
+        Ok(transactions.values().filter(|t| t.from_address == from_address && t.nonce == nonce).cloned().collect())
+    }
+
+    #[instrument(name = "transaction_repository.expire_pending", level = "debug", skip_all, fields(submitted_before = %submitted_before))]
+    async fn expire_pending(&self, submitted_before: NaiveDateTime) -> RepositoryResult<Vec<Transaction>> {
+        let mut transactions = self.transactions.write().unwrap();
+        let mut expired = Vec::new();
+        for transaction in transactions.values_mut() {
+            if transaction.is_pending() && transaction.timestamp.is_some_and(|timestamp| timestamp < submitted_before) {
+                transaction.status = TransactionStatus::EXPIRED;
+                expired.push(transaction.clone());
+            }
+        }
+        Ok(expired)
+    }
+
+    #[instrument(name = "transaction_repository.cancel_pending", level = "debug", skip_all, fields(transaction_id))]
+    async fn cancel_pending(&self, transaction_id: i32) -> RepositoryResult<Transaction> {
+        let mut transactions = self.transactions.write().unwrap();
+        let transaction = transactions.get_mut(&transaction_id).filter(|t| t.is_pending()).ok_or_else(not_found)?;
+        transaction.status = TransactionStatus::CANCELLED;
+        Ok(transaction.clone())
+    }
+
+    #[instrument(name = "transaction_repository.list_by_block", level = "debug", skip_all, fields(block_number))]
+    async fn list_by_block(&self, block_number: i32) -> RepositoryResult<Vec<Transaction>> {
+        let transactions = self.transactions.read().unwrap();
+        Ok(transactions.values().filter(|t| t.block_number == Some(block_number)).cloned().collect())
+    }
+
+    #[instrument(name = "transaction_repository.get", level = "debug", skip_all, fields(transaction_hash = %transaction_hash))]
+    async fn get(&self, transaction_hash: &str) -> RepositoryResult<Transaction> {
+        let transactions = self.transactions.read().unwrap();
+        transactions.values().find(|t| t.transaction_hash == transaction_hash).cloned().ok_or_else(not_found)
+    }
+
+    #[instrument(name = "transaction_repository.get_by_idempotency_key", level = "debug", skip_all, fields(from_address = %from_address))]
+    async fn get_by_idempotency_key(&self, from_address: &str, idempotency_key: &str) -> RepositoryResult<Option<Transaction>> {
+        let transactions = self.transactions.read().unwrap();
+        Ok(transactions
+            .values()
+            .find(|t| t.from_address == from_address && t.idempotency_key.as_deref() == Some(idempotency_key))
+            .cloned())
+    }
+
+    #[instrument(name = "transaction_repository.clear_idempotency_key", level = "debug", skip_all, fields(transaction_id))]
+    async fn clear_idempotency_key(&self, transaction_id: i32) -> RepositoryResult<()> {
+        let mut transactions = self.transactions.write().unwrap();
+        let transaction = transactions.get_mut(&transaction_id).ok_or_else(not_found)?;
+        transaction.idempotency_key = None;
+        Ok(())
+    }
+
+    #[instrument(name = "transaction_repository.mine_pending", level = "debug", skip_all, fields(transaction_id))]
+    async fn mine_pending(&self, transaction_id: i32, update_data: UpdateTransaction) -> RepositoryResult<Option<Transaction>> {
+        let mut transactions = self.transactions.write().unwrap();
+        let Some(transaction) = transactions.get_mut(&transaction_id).filter(|t| t.is_pending()) else {
+            return Ok(None);
+        };
+        if let Some(block_number) = update_data.block_number {
+            transaction.block_number = Some(block_number);
+        }
+        if let Some(is_mined) = update_data.is_mined {
+            transaction.is_mined = Some(is_mined);
+        }
+        if let Some(status) = update_data.status {
+            transaction.status = status;
+        }
+        if let Some(created_token_address) = update_data.created_token_address {
+            transaction.created_token_address = Some(created_token_address);
+        }
+        Ok(Some(transaction.clone()))
+    }
+}
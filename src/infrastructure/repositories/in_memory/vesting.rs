@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+use crate::domain::models::vesting::{CreateVestingSchedule, VestingSchedule};
+use crate::domain::repositories::repository::RepositoryResult;
+use crate::domain::repositories::vesting::VestingRepository;
+use crate::infrastructure::repositories::in_memory::not_found;
+use tracing::instrument;
+
+#[derive(Default)]
+pub struct VestingInMemoryRepository {
+    vesting_schedules: RwLock<BTreeMap<i32, VestingSchedule>>,
+}
+
+impl VestingInMemoryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl VestingRepository for VestingInMemoryRepository {
+    #[instrument(name = "vesting_repository.create", level = "debug", skip_all, fields(transaction_hash = %new_schedule.transaction_hash))]
+    async fn create(&self, new_schedule: &CreateVestingSchedule) -> RepositoryResult<VestingSchedule> {
+        let mut vesting_schedules = self.vesting_schedules.write().unwrap();
+        let id = vesting_schedules.keys().next_back().map(|last| last + 1).unwrap_or(1);
+        let schedule = VestingSchedule {
+            id,
+            token_address: new_schedule.token_address.clone(),
+            grantor_address: new_schedule.grantor_address.clone(),
+            beneficiary_address: new_schedule.beneficiary_address.clone(),
+            unit: new_schedule.unit,
+            start: new_schedule.start,
+            cliff: new_schedule.cliff,
+            duration: new_schedule.duration,
+            total_amount: new_schedule.total_amount,
+            claimed_amount: 0,
+            block_number: new_schedule.block_number,
+            transaction_hash: new_schedule.transaction_hash.clone(),
+        };
+        vesting_schedules.insert(id, schedule.clone());
+        Ok(schedule)
+    }
+
+    #[instrument(name = "vesting_repository.list", level = "debug", skip_all, fields(beneficiary_address = %beneficiary_address))]
+    async fn list(&self, beneficiary_address: &str, token_address: Option<&str>) -> RepositoryResult<Vec<VestingSchedule>> {
+        let vesting_schedules = self.vesting_schedules.read().unwrap();
+        Ok(vesting_schedules
+            .values()
+            .filter(|s| s.beneficiary_address == beneficiary_address)
+            .filter(|s| token_address.is_none_or(|token_address| s.token_address == token_address))
+            .cloned()
+            .collect())
+    }
+
+    #[instrument(name = "vesting_repository.update_claimed", level = "debug", skip_all, fields(schedule_id, claimed_amount))]
+    async fn update_claimed(&self, schedule_id: i32, claimed_amount: i64) -> RepositoryResult<VestingSchedule> {
+        let mut vesting_schedules = self.vesting_schedules.write().unwrap();
+        let schedule = vesting_schedules.get_mut(&schedule_id).ok_or_else(not_found)?;
+        schedule.claimed_amount = claimed_amount;
+        Ok(schedule.clone())
+    }
+}
@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+use crate::domain::models::wallet::Wallet;
+use crate::domain::repositories::repository::{QueryParams, RepositoryResult, ResultPaging};
+use crate::domain::repositories::wallet::{WalletQueryParams, WalletRepository};
+use crate::infrastructure::repositories::in_memory::not_found;
+use tracing::instrument;
+
+#[derive(Default)]
+pub struct WalletInMemoryRepository {
+    // Keyed by (address, token_address) like the wallets table
+    wallets: RwLock<BTreeMap<(String, String), Wallet>>,
+}
+
+impl WalletInMemoryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl WalletRepository for WalletInMemoryRepository {
+    #[instrument(name = "wallet_repository.list", level = "debug", skip_all, fields(params = ?params))]
+    async fn list(&self, params: WalletQueryParams) -> RepositoryResult<ResultPaging<Wallet>> {
+        let wallets = self.wallets.read().unwrap();
+        let items: Vec<Wallet> = wallets
+            .values()
+            .rev()
+            .filter(|w| params.address.as_ref().is_none_or(|address| &w.address == address))
+            .filter(|w| params.token_address.as_ref().is_none_or(|token_address| &w.token_address == token_address))
+            .skip(params.offset() as usize)
+            .take(params.limit() as usize)
+            .cloned()
+            .collect();
+        Ok(ResultPaging { total: items.len() as i64, items })
+    }
+
+    #[instrument(name = "wallet_repository.create_or_update", level = "debug", skip_all, fields(wallet_address = %updated_wallet.address, token_address = %updated_wallet.token_address))]
+    async fn create_or_update(&self, updated_wallet: &Wallet) -> RepositoryResult<Wallet> {
+        let key = (updated_wallet.address.to_string(), updated_wallet.token_address.to_string());
+        self.wallets.write().unwrap().insert(key, updated_wallet.clone());
+        Ok(updated_wallet.clone())
+    }
+
+    #[instrument(name = "wallet_repository.create_or_update_many", level = "debug", skip_all, fields(count = updated_wallets.len()))]
+    async fn create_or_update_many(&self, updated_wallets: &[Wallet]) -> RepositoryResult<Vec<Wallet>> {
+        let mut wallets = self.wallets.write().unwrap();
+        for updated_wallet in updated_wallets {
+            wallets.insert((updated_wallet.address.to_string(), updated_wallet.token_address.to_string()), updated_wallet.clone());
+        }
+        Ok(updated_wallets.to_vec())
+    }
+
+    #[instrument(name = "wallet_repository.get", level = "debug", skip_all, fields(wallet_address = %wallet_address, token_address = %token_address))]
+    async fn get(&self, wallet_address: &str, token_address: &str) -> RepositoryResult<Wallet> {
+        let wallets = self.wallets.read().unwrap();
+        wallets.get(&(wallet_address.to_string(), token_address.to_string())).cloned().ok_or_else(not_found)
+    }
+}
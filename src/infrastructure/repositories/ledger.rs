@@ -0,0 +1,108 @@
+use actix_threadpool::{run, BlockingError};
+use async_trait::async_trait;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+use crate::domain::models::ledger::{no_longer_pending, LedgerChanges};
+use crate::domain::models::transaction::TransactionStatus;
+use crate::domain::repositories::ledger::LedgerRepository;
+use crate::domain::repositories::repository::RepositoryResult;
+use crate::infrastructure::databases::connection::DBConn;
+use crate::infrastructure::error::DieselRepositoryError;
+use crate::infrastructure::models::block::CreateBlockDiesel;
+use crate::infrastructure::models::fungible_token::{CreateOwnershipChangeDiesel, FungibleTokenDiesel, UpdatedFungibleTokenDiesel};
+use crate::infrastructure::models::transaction::UpdateTransactionDiesel;
+use crate::infrastructure::models::vesting::{CreateVestingScheduleDiesel, VestingScheduleDiesel};
+use crate::infrastructure::models::wallet::WalletDiesel;
+use crate::infrastructure::repositories::wallet::upsert_wallet;
+use tracing::instrument;
+
+pub struct LedgerDieselRepository {
+    pub pool: Arc<DBConn>,
+}
+
+impl LedgerDieselRepository {
+    pub fn new(db: Arc<DBConn>) -> Self {
+        LedgerDieselRepository { pool: db }
+    }
+}
+
+#[async_trait]
+impl LedgerRepository for LedgerDieselRepository {
+    #[instrument(name = "ledger_repository.commit", level = "debug", skip_all)]
+    async fn commit(&self, changes: &LedgerChanges) -> RepositoryResult<()> {
+        use crate::infrastructure::schema::blocks::dsl::blocks;
+        use crate::infrastructure::schema::fungible_tokens::dsl::{address, fungible_tokens, owner_address};
+        use crate::infrastructure::schema::token_ownership_changes::dsl::token_ownership_changes;
+        use crate::infrastructure::schema::transactions::dsl::{id as transaction_id, is_mined, status, transactions};
+        use crate::infrastructure::schema::vesting_schedules::dsl::{claimed_amount, id as vesting_schedule_id, vesting_schedules};
+        let new_block_diesel: Option<CreateBlockDiesel> = changes.block.clone().map(CreateBlockDiesel::from);
+        let update_transaction_diesel: Option<(i32, UpdateTransactionDiesel)> = changes.transaction.clone().map(|(id, update)| (id, UpdateTransactionDiesel::from(update)));
+        let created_tokens_diesel: Vec<FungibleTokenDiesel> = changes.created_tokens.iter().cloned().map(FungibleTokenDiesel::from).collect();
+        let updated_tokens_diesel: Vec<(String, UpdatedFungibleTokenDiesel)> = changes
+            .updated_tokens
+            .iter()
+            .cloned()
+            .map(|(token_address, updated_token)| (token_address, UpdatedFungibleTokenDiesel::from(updated_token)))
+            .collect();
+        let ownership_changes_diesel: Vec<CreateOwnershipChangeDiesel> = changes.ownership_changes.iter().cloned().map(CreateOwnershipChangeDiesel::from).collect();
+        let created_vesting_schedules_diesel: Vec<CreateVestingScheduleDiesel> = changes.created_vesting_schedules.iter().cloned().map(CreateVestingScheduleDiesel::from).collect();
+        let vesting_claims = changes.vesting_claims.clone();
+        let wallets_diesel: Vec<WalletDiesel> = changes.wallets.iter().cloned().map(WalletDiesel::from).collect();
+        let mut conn = self.pool.get().unwrap();
+
+        // A single database transaction, rolled back as a whole on the first failing write
+        run(move || {
+            conn.transaction(|conn| {
+                // Claims the transaction first, so one cancelled, expired or mined by another block rolls everything back
+                if let Some((requested_transaction_id, update_transaction_diesel)) = &update_transaction_diesel {
+                    let claimed_count = diesel::update(
+                        transactions
+                            .filter(transaction_id.eq(requested_transaction_id))
+                            .filter(is_mined.eq(false))
+                            .filter(status.eq(TransactionStatus::RAW.to_string())),
+                    )
+                    .set(update_transaction_diesel)
+                    .execute(conn)?;
+                    if claimed_count == 0 {
+                        return Err(DieselRepositoryError::from(no_longer_pending(*requested_transaction_id)));
+                    }
+                }
+                for created_token_diesel in &created_tokens_diesel {
+                    diesel::insert_into(fungible_tokens).values(created_token_diesel).execute(conn)?;
+                }
+                for (token_address, updated_token_diesel) in &updated_tokens_diesel {
+                    diesel::update(fungible_tokens.filter(address.eq(token_address)))
+                        .set(updated_token_diesel)
+                        .get_result::<FungibleTokenDiesel>(conn)?;
+                }
+                for ownership_change_diesel in &ownership_changes_diesel {
+                    diesel::update(fungible_tokens.filter(address.eq(&ownership_change_diesel.token_address)))
+                        .set(owner_address.eq(&ownership_change_diesel.new_owner_address))
+                        .get_result::<FungibleTokenDiesel>(conn)?;
+                    diesel::insert_into(token_ownership_changes).values(ownership_change_diesel).execute(conn)?;
+                }
+                for created_vesting_schedule_diesel in &created_vesting_schedules_diesel {
+                    diesel::insert_into(vesting_schedules).values(created_vesting_schedule_diesel).execute(conn)?;
+                }
+                for (schedule_id, new_claimed_amount) in &vesting_claims {
+                    diesel::update(vesting_schedules.filter(vesting_schedule_id.eq(schedule_id)))
+                        .set(claimed_amount.eq(new_claimed_amount))
+                        .get_result::<VestingScheduleDiesel>(conn)?;
+                }
+                for wallet_diesel in &wallets_diesel {
+                    upsert_wallet(conn, wallet_diesel)?;
+                }
+                if let Some(new_block_diesel) = &new_block_diesel {
+                    diesel::insert_into(blocks).values(new_block_diesel).execute(conn)?;
+                }
+                Ok::<(), DieselRepositoryError>(())
+            })
+        })
+        .await
+        .map_err(|v| match v {
+            BlockingError::Error(e) => e.into_inner(),
+            BlockingError::Canceled => DieselRepositoryError::from(v).into_inner(),
+        })
+    }
+}
@@ -1,4 +1,7 @@
 pub mod block;
 pub mod fungible_token;
+pub mod in_memory;
+pub mod ledger;
 pub mod transaction;
+pub mod vesting;
 pub mod wallet;
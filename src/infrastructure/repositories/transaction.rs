@@ -1,14 +1,16 @@
 use actix_threadpool::run;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use diesel::prelude::*;
 use std::sync::Arc;
 
-use crate::domain::models::transaction::{CreateTransaction, Transaction, UpdateTransaction};
+use crate::domain::models::transaction::{CreateTransaction, Transaction, TransactionStatus, UpdateTransaction};
 use crate::domain::repositories::repository::{QueryParams, RepositoryResult, ResultPaging};
 use crate::domain::repositories::transaction::{TransactionQueryParams, TransactionRepository};
-use crate::infrastructure::databases::postgresql::DBConn;
+use crate::infrastructure::databases::connection::DBConn;
 use crate::infrastructure::error::DieselRepositoryError;
 use crate::infrastructure::models::transaction::{CreateTransactionDiesel, TransactionDiesel, UpdateTransactionDiesel};
+use tracing::instrument;
 
 pub struct TransactionDieselRepository {
     pub pool: Arc<DBConn>,
@@ -22,6 +24,7 @@ impl TransactionDieselRepository {
 
 #[async_trait]
 impl TransactionRepository for TransactionDieselRepository {
+    #[instrument(name = "transaction_repository.create", level = "debug", skip_all, fields(transaction_hash = %new_transaction.transaction_hash))]
     async fn create(&self, new_transaction: &CreateTransaction) -> RepositoryResult<Transaction> {
         use crate::infrastructure::schema::transactions::dsl::transactions;
         let new_transaction_diesel: CreateTransactionDiesel = CreateTransactionDiesel::from(new_transaction.clone());
@@ -29,9 +32,44 @@ impl TransactionRepository for TransactionDieselRepository {
         let result: TransactionDiesel = run(move || diesel::insert_into(transactions).values(new_transaction_diesel).get_result(&mut conn))
             .await
             .map_err(|v| DieselRepositoryError::from(v).into_inner())?;
-        Ok(result.into())
+        result.try_into()
     }
 
+    #[instrument(name = "transaction_repository.create_many", level = "debug", skip_all, fields(count = new_transactions.len()))]
+    async fn create_many(&self, new_transactions: &[CreateTransaction], replaced_transaction_ids: &[i32]) -> RepositoryResult<Vec<Transaction>> {
+        use crate::infrastructure::schema::transactions::dsl::{id, is_mined, status, transactions};
+        let new_transactions_diesel: Vec<CreateTransactionDiesel> = new_transactions.iter().cloned().map(CreateTransactionDiesel::from).collect();
+        let replaced_transaction_ids = replaced_transaction_ids.to_vec();
+        let mut conn = self.pool.get().unwrap();
+        // Inserted one by one inside a single database transaction, so either every row is stored or none
+        let result: Vec<TransactionDiesel> = run(move || {
+            conn.transaction(|conn| {
+                if !replaced_transaction_ids.is_empty() {
+                    let replaced_count = diesel::update(
+                        transactions
+                            .filter(id.eq_any(&replaced_transaction_ids))
+                            .filter(is_mined.eq(false))
+                            .filter(status.eq(TransactionStatus::RAW.to_string())),
+                    )
+                    .set(status.eq(TransactionStatus::REPLACED.to_string()))
+                    .execute(conn)?;
+                    // A replaced transaction was mined or dropped in the meantime
+                    if replaced_count != replaced_transaction_ids.len() {
+                        return Err(diesel::result::Error::NotFound);
+                    }
+                }
+                new_transactions_diesel
+                    .into_iter()
+                    .map(|new_transaction_diesel| diesel::insert_into(transactions).values(new_transaction_diesel).get_result(conn))
+                    .collect::<QueryResult<Vec<TransactionDiesel>>>()
+            })
+        })
+        .await
+        .map_err(|v| DieselRepositoryError::from(v).into_inner())?;
+        result.into_iter().map(Transaction::try_from).collect()
+    }
+
+    #[instrument(name = "transaction_repository.list", level = "debug", skip_all, fields(params = ?params))]
     async fn list(&self, params: TransactionQueryParams) -> RepositoryResult<ResultPaging<Transaction>> {
         use crate::infrastructure::schema::transactions::dsl::{is_mined, transactions};
         let pool = self.pool.clone();
@@ -45,10 +83,136 @@ impl TransactionRepository for TransactionDieselRepository {
         .map_err(|v| DieselRepositoryError::from(v).into_inner())?;
         Ok(ResultPaging {
             total: result.len() as i64,
-            items: result.into_iter().map(|v| v.into()).collect(),
+            items: result.into_iter().map(Transaction::try_from).collect::<RepositoryResult<Vec<Transaction>>>()?,
+        })
+    }
+
+    #[instrument(name = "transaction_repository.list_pending", level = "debug", skip_all)]
+    async fn list_pending(&self) -> RepositoryResult<Vec<Transaction>> {
+        use crate::infrastructure::schema::transactions::dsl::{id, is_mined, status, transactions};
+        let pool = self.pool.clone();
+        let result = run(move || {
+            let mut conn = pool.get().unwrap();
+            transactions
+                .filter(is_mined.eq(false))
+                .filter(status.eq(TransactionStatus::RAW.to_string()))
+                .order_by(id.asc())
+                .load::<TransactionDiesel>(&mut conn)
+        })
+        .await
+        .map_err(|v| DieselRepositoryError::from(v).into_inner())?;
+        result.into_iter().map(Transaction::try_from).collect()
+    }
+
+    #[instrument(name = "transaction_repository.count_pending", level = "debug", skip_all, fields(from_address = ?from_address_val))]
+    async fn count_pending(&self, from_address_val: Option<&str>) -> RepositoryResult<i64> {
+        use crate::infrastructure::schema::transactions::dsl::{from_address, is_mined, status, transactions};
+        let pool = self.pool.clone();
+        let mut builder = transactions.filter(is_mined.eq(false)).filter(status.eq(TransactionStatus::RAW.to_string())).into_boxed();
+        if let Some(requested_from_address) = from_address_val {
+            builder = builder.filter(from_address.eq(requested_from_address.to_string()));
+        }
+        run(move || {
+            let mut conn = pool.get().unwrap();
+            builder.count().get_result::<i64>(&mut conn)
+        })
+        .await
+        .map_err(|v| DieselRepositoryError::from(v).into_inner())
+    }
+
+    #[instrument(name = "transaction_repository.max_nonce", level = "debug", skip_all, fields(from_address = %from_address_val))]
+    async fn max_nonce(&self, from_address_val: &str) -> RepositoryResult<Option<i64>> {
+        use crate::infrastructure::schema::transactions::dsl::{from_address, nonce, status, transactions};
+        let pool = self.pool.clone();
+        let requested_from_address = from_address_val.to_string();
+        let dropped_statuses = [
+            TransactionStatus::EXPIRED.to_string(),
+            TransactionStatus::REPLACED.to_string(),
+            TransactionStatus::CANCELLED.to_string(),
+        ];
+        run(move || {
+            let mut conn = pool.get().unwrap();
+            transactions
+                .filter(from_address.eq(requested_from_address))
+                .filter(status.ne_all(dropped_statuses))
+                .select(diesel::dsl::max(nonce))
+                .first::<Option<i64>>(&mut conn)
+        })
+        .await
+        .map_err(|v| DieselRepositoryError::from(v).into_inner())
+    }
+
+    #[instrument(name = "transaction_repository.list_by_nonce", level = "debug", skip_all, fields(from_address = %from_address_val, nonce = nonce_val))]
+    async fn list_by_nonce(&self, from_address_val: &str, nonce_val: i64) -> RepositoryResult<Vec<Transaction>> {
+        use crate::infrastructure::schema::transactions::dsl::{from_address, id, nonce, transactions};
+        let pool = self.pool.clone();
+        let requested_from_address = from_address_val.to_string();
+        let result = run(move || {
+            let mut conn = pool.get().unwrap();
+            transactions
+                .filter(from_address.eq(requested_from_address))
+                .filter(nonce.eq(nonce_val))
+                .order_by(id.asc())
+                .load::<TransactionDiesel>(&mut conn)
+        })
+        .await
+        .map_err(|v| DieselRepositoryError::from(v).into_inner())?;
+        result.into_iter().map(Transaction::try_from).collect()
+    }
+
+    #[instrument(name = "transaction_repository.expire_pending", level = "debug", skip_all, fields(submitted_before = %submitted_before))]
+    async fn expire_pending(&self, submitted_before: NaiveDateTime) -> RepositoryResult<Vec<Transaction>> {
+        use crate::infrastructure::schema::transactions::dsl::{is_mined, status, timestamp, transactions};
+        let mut conn = self.pool.get().unwrap();
+        let result = run(move || {
+            diesel::update(
+                transactions
+                    .filter(is_mined.eq(false))
+                    .filter(status.eq(TransactionStatus::RAW.to_string()))
+                    .filter(timestamp.lt(submitted_before)),
+            )
+            .set(status.eq(TransactionStatus::EXPIRED.to_string()))
+            .get_results::<TransactionDiesel>(&mut conn)
+        })
+        .await
+        .map_err(|v| DieselRepositoryError::from(v).into_inner())?;
+        result.into_iter().map(Transaction::try_from).collect()
+    }
+
+    #[instrument(name = "transaction_repository.cancel_pending", level = "debug", skip_all, fields(transaction_id))]
+    async fn cancel_pending(&self, transaction_id: i32) -> RepositoryResult<Transaction> {
+        use crate::infrastructure::schema::transactions::dsl::{id, is_mined, status, transactions};
+        let mut conn = self.pool.get().unwrap();
+        // Fails with NotFound when the transaction was mined or dropped in the meantime
+        let result: TransactionDiesel = run(move || {
+            diesel::update(
+                transactions
+                    .filter(id.eq(transaction_id))
+                    .filter(is_mined.eq(false))
+                    .filter(status.eq(TransactionStatus::RAW.to_string())),
+            )
+            .set(status.eq(TransactionStatus::CANCELLED.to_string()))
+            .get_result(&mut conn)
         })
+        .await
+        .map_err(|v| DieselRepositoryError::from(v).into_inner())?;
+        result.try_into()
     }
 
+    #[instrument(name = "transaction_repository.list_by_block", level = "debug", skip_all, fields(block_number = block_number_val))]
+    async fn list_by_block(&self, block_number_val: i32) -> RepositoryResult<Vec<Transaction>> {
+        use crate::infrastructure::schema::transactions::dsl::{block_number, id, transactions};
+        let pool = self.pool.clone();
+        let result = run(move || {
+            let mut conn = pool.get().unwrap();
+            transactions.filter(block_number.eq(block_number_val)).order_by(id.asc()).load::<TransactionDiesel>(&mut conn)
+        })
+        .await
+        .map_err(|v| DieselRepositoryError::from(v).into_inner())?;
+        result.into_iter().map(Transaction::try_from).collect()
+    }
+
+    #[instrument(name = "transaction_repository.get", level = "debug", skip_all, fields(transaction_hash = %transaction_hash_val))]
     async fn get(&self, transaction_hash_val: &str) -> RepositoryResult<Transaction> {
         use crate::infrastructure::schema::transactions::dsl::{transaction_hash, transactions};
         let mut conn = self.pool.get().unwrap();
@@ -56,29 +220,55 @@ impl TransactionRepository for TransactionDieselRepository {
         run(move || transactions.filter(transaction_hash.eq(requested_hash.as_ref())).first::<TransactionDiesel>(&mut conn))
             .await
             .map_err(|v| DieselRepositoryError::from(v).into_inner())
-            .map(|v| -> Transaction { v.into() })
+            .and_then(Transaction::try_from)
+    }
+
+    #[instrument(name = "transaction_repository.get_by_idempotency_key", level = "debug", skip_all, fields(from_address = %from_address_val))]
+    async fn get_by_idempotency_key(&self, from_address_val: &str, idempotency_key_val: &str) -> RepositoryResult<Option<Transaction>> {
+        use crate::infrastructure::schema::transactions::dsl::{from_address, idempotency_key, transactions};
+        let mut conn = self.pool.get().unwrap();
+        let requested_from_address = from_address_val.to_string();
+        let requested_key = idempotency_key_val.to_string();
+        run(move || {
+            transactions
+                .filter(from_address.eq(requested_from_address))
+                .filter(idempotency_key.eq(requested_key))
+                .first::<TransactionDiesel>(&mut conn)
+                .optional()
+        })
+        .await
+        .map_err(|v| DieselRepositoryError::from(v).into_inner())
+        .and_then(|v| v.map(Transaction::try_from).transpose())
     }
 
-    async fn delete(&self, transaction_id: i32) -> RepositoryResult<()> {
-        use crate::infrastructure::schema::transactions::dsl::{id, transactions};
+    #[instrument(name = "transaction_repository.clear_idempotency_key", level = "debug", skip_all, fields(transaction_id))]
+    async fn clear_idempotency_key(&self, transaction_id: i32) -> RepositoryResult<()> {
+        use crate::infrastructure::schema::transactions::dsl::{id, idempotency_key, transactions};
         let mut conn = self.pool.get().unwrap();
-        run(move || diesel::delete(transactions).filter(id.eq(transaction_id)).execute(&mut conn))
+        run(move || diesel::update(transactions.filter(id.eq(transaction_id))).set(idempotency_key.eq(None::<String>)).execute(&mut conn))
             .await
             .map_err(|v| DieselRepositoryError::from(v).into_inner())?;
         Ok(())
     }
 
-    async fn update(&self, transaction_id: i32, update_data: UpdateTransaction) -> RepositoryResult<Transaction> {
-        use crate::infrastructure::schema::transactions::dsl::{id, transactions};
+    #[instrument(name = "transaction_repository.mine_pending", level = "debug", skip_all, fields(transaction_id))]
+    async fn mine_pending(&self, transaction_id: i32, update_data: UpdateTransaction) -> RepositoryResult<Option<Transaction>> {
+        use crate::infrastructure::schema::transactions::dsl::{id, is_mined, status, transactions};
         let update_transaction_diesel = UpdateTransactionDiesel::from(update_data);
         let mut conn = self.pool.get().unwrap();
         let updated_transaction = run(move || {
-            diesel::update(transactions.filter(id.eq(transaction_id)))
-                .set(&update_transaction_diesel)
-                .get_result::<TransactionDiesel>(&mut conn)
+            diesel::update(
+                transactions
+                    .filter(id.eq(transaction_id))
+                    .filter(is_mined.eq(false))
+                    .filter(status.eq(TransactionStatus::RAW.to_string())),
+            )
+            .set(&update_transaction_diesel)
+            .get_result::<TransactionDiesel>(&mut conn)
+            .optional()
         })
         .await
         .map_err(|v| DieselRepositoryError::from(v).into_inner())?;
-        Ok(updated_transaction.into())
+        updated_transaction.map(Transaction::try_from).transpose()
     }
 }
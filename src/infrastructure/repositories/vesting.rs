@@ -0,0 +1,70 @@
+use actix_threadpool::run;
+use async_trait::async_trait;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+use crate::domain::models::vesting::{CreateVestingSchedule, VestingSchedule};
+use crate::domain::repositories::repository::RepositoryResult;
+use crate::domain::repositories::vesting::VestingRepository;
+use crate::infrastructure::databases::connection::DBConn;
+use crate::infrastructure::error::DieselRepositoryError;
+use crate::infrastructure::models::vesting::{CreateVestingScheduleDiesel, VestingScheduleDiesel};
+use tracing::instrument;
+
+pub struct VestingDieselRepository {
+    pub pool: Arc<DBConn>,
+}
+
+impl VestingDieselRepository {
+    pub fn new(db: Arc<DBConn>) -> Self {
+        VestingDieselRepository { pool: db }
+    }
+}
+
+#[async_trait]
+impl VestingRepository for VestingDieselRepository {
+    #[instrument(name = "vesting_repository.create", level = "debug", skip_all, fields(transaction_hash = %new_schedule.transaction_hash))]
+    async fn create(&self, new_schedule: &CreateVestingSchedule) -> RepositoryResult<VestingSchedule> {
+        use crate::infrastructure::schema::vesting_schedules::dsl::vesting_schedules;
+        let new_schedule_diesel = CreateVestingScheduleDiesel::from(new_schedule.clone());
+        let mut conn = self.pool.get().unwrap();
+        let result: VestingScheduleDiesel = run(move || diesel::insert_into(vesting_schedules).values(new_schedule_diesel).get_result(&mut conn))
+            .await
+            .map_err(|v| DieselRepositoryError::from(v).into_inner())?;
+        Ok(result.into())
+    }
+
+    #[instrument(name = "vesting_repository.list", level = "debug", skip_all, fields(beneficiary_address = %requested_beneficiary_address))]
+    async fn list(&self, requested_beneficiary_address: &str, requested_token_address: Option<&str>) -> RepositoryResult<Vec<VestingSchedule>> {
+        use crate::infrastructure::schema::vesting_schedules::dsl::{beneficiary_address, id, token_address, vesting_schedules};
+        let pool = self.pool.clone();
+        let mut builder = vesting_schedules
+            .filter(beneficiary_address.eq(requested_beneficiary_address.to_string()))
+            .order_by(id.asc())
+            .into_boxed();
+        if let Some(requested_token_address) = requested_token_address {
+            builder = builder.filter(token_address.eq(requested_token_address.to_string()));
+        }
+        let result = run(move || {
+            let mut conn = pool.get().unwrap();
+            builder.load::<VestingScheduleDiesel>(&mut conn)
+        })
+        .await
+        .map_err(|v| DieselRepositoryError::from(v).into_inner())?;
+        Ok(result.into_iter().map(|v| v.into()).collect())
+    }
+
+    #[instrument(name = "vesting_repository.update_claimed", level = "debug", skip_all, fields(schedule_id, claimed_amount = new_claimed_amount))]
+    async fn update_claimed(&self, schedule_id: i32, new_claimed_amount: i64) -> RepositoryResult<VestingSchedule> {
+        use crate::infrastructure::schema::vesting_schedules::dsl::{claimed_amount, id, vesting_schedules};
+        let mut conn = self.pool.get().unwrap();
+        let result: VestingScheduleDiesel = run(move || {
+            diesel::update(vesting_schedules.filter(id.eq(schedule_id)))
+                .set(claimed_amount.eq(new_claimed_amount))
+                .get_result(&mut conn)
+        })
+        .await
+        .map_err(|v| DieselRepositoryError::from(v).into_inner())?;
+        Ok(result.into())
+    }
+}
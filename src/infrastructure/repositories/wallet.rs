@@ -6,9 +6,10 @@ use std::sync::Arc;
 use crate::domain::models::wallet::Wallet;
 use crate::domain::repositories::repository::{QueryParams, RepositoryResult, ResultPaging};
 use crate::domain::repositories::wallet::{WalletQueryParams, WalletRepository};
-use crate::infrastructure::databases::postgresql::DBConn;
+use crate::infrastructure::databases::connection::{DBConn, DbConnection};
 use crate::infrastructure::error::DieselRepositoryError;
 use crate::infrastructure::models::wallet::WalletDiesel;
+use tracing::instrument;
 
 pub struct WalletDieselRepository {
     pub pool: Arc<DBConn>,
@@ -20,31 +21,68 @@ impl WalletDieselRepository {
     }
 }
 
+// Updates the existing wallet or inserts it, an upsert that every supported backend understands
+pub(crate) fn upsert_wallet(conn: &mut DbConnection, updated_wallet_diesel: &WalletDiesel) -> QueryResult<WalletDiesel> {
+    use crate::infrastructure::schema::wallets::dsl::{address, token_address, wallets};
+    let updated_wallet = diesel::update(
+        wallets
+            .filter(address.eq(&updated_wallet_diesel.address))
+            .filter(token_address.eq(&updated_wallet_diesel.token_address)),
+    )
+    .set(updated_wallet_diesel)
+    .get_result::<WalletDiesel>(conn)
+    .optional()?;
+    match updated_wallet {
+        Some(wallet) => Ok(wallet),
+        None => diesel::insert_into(wallets).values(updated_wallet_diesel).get_result(conn),
+    }
+}
+
 #[async_trait]
 impl WalletRepository for WalletDieselRepository {
+    #[instrument(name = "wallet_repository.create_or_update", level = "debug", skip_all, fields(wallet_address = %updated_wallet.address, token_address = %updated_wallet.token_address))]
     async fn create_or_update(&self, updated_wallet: &Wallet) -> RepositoryResult<Wallet> {
-        use crate::infrastructure::schema::wallets::dsl::{address, token_address, wallets};
         let updated_wallet_diesel: WalletDiesel = WalletDiesel::from(updated_wallet.clone());
         let mut conn = self.pool.get().unwrap();
 
-        let result: WalletDiesel = run(move || {
-            diesel::insert_into(wallets)
-                .values(updated_wallet_diesel.clone())
-                .on_conflict((address, token_address)) // Specify the column for conflict detection
-                .do_update() // Perform update if conflict is detected
-                .set(updated_wallet_diesel.clone()) // Set the values to update with
-                .get_result(&mut conn)
+        let result: WalletDiesel = run(move || conn.transaction(|conn| upsert_wallet(conn, &updated_wallet_diesel)))
+            .await
+            .map_err(|v| DieselRepositoryError::from(v).into_inner())?;
+
+        Ok(result.into())
+    }
+
+    #[instrument(name = "wallet_repository.create_or_update_many", level = "debug", skip_all, fields(count = updated_wallets.len()))]
+    async fn create_or_update_many(&self, updated_wallets: &[Wallet]) -> RepositoryResult<Vec<Wallet>> {
+        let updated_wallets_diesel: Vec<WalletDiesel> = updated_wallets.iter().cloned().map(WalletDiesel::from).collect();
+        let mut conn = self.pool.get().unwrap();
+
+        // A single database transaction, so either every wallet is stored or none
+        let result: Vec<WalletDiesel> = run(move || {
+            conn.transaction(|conn| {
+                updated_wallets_diesel
+                    .iter()
+                    .map(|updated_wallet_diesel| upsert_wallet(conn, updated_wallet_diesel))
+                    .collect::<QueryResult<Vec<WalletDiesel>>>()
+            })
         })
         .await
         .map_err(|v| DieselRepositoryError::from(v).into_inner())?;
 
-        Ok(result.into())
+        Ok(result.into_iter().map(|v| v.into()).collect())
     }
 
+    #[instrument(name = "wallet_repository.list", level = "debug", skip_all, fields(params = ?params))]
     async fn list(&self, params: WalletQueryParams) -> RepositoryResult<ResultPaging<Wallet>> {
-        use crate::infrastructure::schema::wallets::dsl::{address, wallets};
+        use crate::infrastructure::schema::wallets::dsl::{address, token_address, wallets};
         let pool = self.pool.clone();
-        let builder = wallets.limit(params.limit()).offset(params.offset()).order_by(address.desc()); // Add order_by clause
+        let mut builder = wallets.limit(params.limit()).offset(params.offset()).order_by(address.desc()).into_boxed(); // Add order_by clause
+        if let Some(requested_address) = params.address {
+            builder = builder.filter(address.eq(String::from(requested_address)));
+        }
+        if let Some(requested_token_address) = params.token_address {
+            builder = builder.filter(token_address.eq(String::from(requested_token_address)));
+        }
         let result = run(move || {
             let mut conn = pool.get().unwrap();
             builder.load::<WalletDiesel>(&mut conn)
@@ -57,6 +95,7 @@ impl WalletRepository for WalletDieselRepository {
         })
     }
 
+    #[instrument(name = "wallet_repository.get", level = "debug", skip_all, fields(wallet_address = %requested_address, token_address = %requested_token_address))]
     async fn get(&self, requested_address: &str, requested_token_address: &str) -> RepositoryResult<Wallet> {
         use crate::infrastructure::schema::wallets::dsl::{address, token_address, wallets};
         let mut conn = self.pool.get().unwrap();
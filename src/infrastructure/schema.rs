@@ -41,6 +41,11 @@ diesel::table! {
         block_number -> Int4,
         #[max_length = 66]
         transaction_hash -> Varchar,
+        minter_addresses -> Text,
+        max_supply -> Nullable<Int8>,
+        mintable -> Bool,
+        burnable -> Bool,
+        paused -> Bool,
     }
 }
 
@@ -51,6 +56,21 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    token_ownership_changes (id) {
+        id -> Int4,
+        #[max_length = 42]
+        token_address -> Varchar,
+        #[max_length = 42]
+        previous_owner_address -> Varchar,
+        #[max_length = 42]
+        new_owner_address -> Varchar,
+        block_number -> Int4,
+        #[max_length = 66]
+        transaction_hash -> Varchar,
+    }
+}
+
 diesel::table! {
     transactions (id) {
         id -> Int4,
@@ -65,10 +85,38 @@ diesel::table! {
         transaction_type -> Varchar,
         value -> Int8,
         timestamp -> Nullable<Timestamp>,
-        data -> Nullable<Json>,
+        data -> Nullable<Text>,
         is_mined -> Nullable<Bool>,
         #[max_length = 42]
         status -> Varchar,
+        #[max_length = 255]
+        idempotency_key -> Nullable<Varchar>,
+        fee -> Int8,
+        nonce -> Int8,
+        #[max_length = 42]
+        created_token_address -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    vesting_schedules (id) {
+        id -> Int4,
+        #[max_length = 42]
+        token_address -> Varchar,
+        #[max_length = 42]
+        grantor_address -> Varchar,
+        #[max_length = 42]
+        beneficiary_address -> Varchar,
+        #[max_length = 16]
+        unit -> Varchar,
+        start -> Int8,
+        cliff -> Int8,
+        duration -> Int8,
+        total_amount -> Int8,
+        claimed_amount -> Int8,
+        block_number -> Int4,
+        #[max_length = 66]
+        transaction_hash -> Varchar,
     }
 }
 
@@ -82,6 +130,7 @@ diesel::table! {
         block_number -> Int4,
         #[max_length = 66]
         transaction_hash -> Varchar,
+        frozen -> Bool,
     }
 }
 
@@ -90,6 +139,8 @@ diesel::allow_tables_to_appear_in_same_query!(
     events,
     fungible_tokens,
     service_contexts,
+    token_ownership_changes,
     transactions,
+    vesting_schedules,
     wallets,
 );
@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+
+use crate::domain::error::CommonError;
+use crate::domain::models::schema::SchemaStatus;
+use crate::domain::services::schema::SchemaService;
+
+// The in-memory repositories have no schema, so there is never anything to migrate
+#[derive(Clone, Default)]
+pub struct SchemaInMemoryServiceImpl {}
+
+impl SchemaInMemoryServiceImpl {
+    pub fn new() -> Self {
+        SchemaInMemoryServiceImpl {}
+    }
+}
+
+#[async_trait]
+impl SchemaService for SchemaInMemoryServiceImpl {
+    async fn check_connection(&self) -> Result<(), CommonError> {
+        Ok(())
+    }
+
+    async fn status(&self) -> Result<SchemaStatus, CommonError> {
+        Ok(SchemaStatus::default())
+    }
+
+    fn migrate(&self) -> Result<SchemaStatus, CommonError> {
+        Ok(SchemaStatus::default())
+    }
+}
@@ -0,0 +1,37 @@
+use std::sync::RwLock;
+
+use crate::domain::models::service_context::ServiceContext;
+use crate::domain::services::service_context::ServiceContextService;
+
+pub struct ServiceContextInMemoryServiceImpl {
+    service_context: RwLock<ServiceContext>,
+}
+
+impl ServiceContextInMemoryServiceImpl {
+    pub fn new() -> Self {
+        ServiceContextInMemoryServiceImpl {
+            service_context: RwLock::new(ServiceContext { id: 1, maintenance: false }),
+        }
+    }
+}
+
+impl Default for ServiceContextInMemoryServiceImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ServiceContextService for ServiceContextInMemoryServiceImpl {
+    fn get_service_context(&self) -> ServiceContext {
+        self.service_context.read().unwrap().clone()
+    }
+
+    fn update(&self, service_context: ServiceContext) -> ServiceContext {
+        *self.service_context.write().unwrap() = service_context.clone();
+        service_context
+    }
+
+    fn is_maintenance_active(&self) -> bool {
+        self.service_context.read().unwrap().maintenance
+    }
+}
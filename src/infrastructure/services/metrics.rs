@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use prometheus::{Encoder, Histogram, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, Opts, Registry, TextEncoder};
+
+use crate::domain::error::CommonError;
+use crate::domain::repositories::fungible_token::FungibleTokenQueryParams;
+use crate::domain::repositories::transaction::TransactionRepository;
+use crate::domain::services::chain::ChainService;
+use crate::domain::services::fungible_token::FungibleTokenService;
+use crate::domain::services::metrics::MetricsService;
+use crate::infrastructure::databases::connection::DBConn;
+
+const TOKEN_PAGE_SIZE: i64 = 100;
+
+// Every container owns its registry, counters are updated as events happen and gauges are refreshed on scrape
+pub struct PrometheusMetricsServiceImpl {
+    registry: Registry,
+    http_requests_total: IntCounterVec,
+    http_request_duration_seconds: HistogramVec,
+    db_pool_connections: IntGaugeVec,
+    mempool_depth: IntGauge,
+    block_height: IntGauge,
+    block_production_seconds: Histogram,
+    transactions_executed_total: IntCounterVec,
+    token_total_supply: IntGaugeVec,
+    pool: Option<Arc<DBConn>>,
+    chain_service: Arc<dyn ChainService>,
+    transaction_repository: Arc<dyn TransactionRepository>,
+    fungible_token_service: Arc<dyn FungibleTokenService>,
+}
+
+impl PrometheusMetricsServiceImpl {
+    pub fn new(pool: Option<Arc<DBConn>>, chain_service: Arc<dyn ChainService>, transaction_repository: Arc<dyn TransactionRepository>, fungible_token_service: Arc<dyn FungibleTokenService>) -> Self {
+        let registry = Registry::new_custom(Some("rustychain".to_string()), None).expect("Failed to create metrics registry");
+        let http_requests_total = IntCounterVec::new(Opts::new("http_requests_total", "HTTP requests by route"), &["method", "route", "status"]).unwrap();
+        let http_request_duration_seconds = HistogramVec::new(HistogramOpts::new("http_request_duration_seconds", "HTTP request latency by route"), &["method", "route"]).unwrap();
+        let db_pool_connections = IntGaugeVec::new(Opts::new("db_pool_connections", "Database pool connections by state"), &["state"]).unwrap();
+        let mempool_depth = IntGauge::new("mempool_depth", "Transactions waiting to be mined").unwrap();
+        let block_height = IntGauge::new("block_height", "Number of the latest block").unwrap();
+        let block_production_seconds = Histogram::with_opts(HistogramOpts::new("block_production_seconds", "Time taken to produce a block")).unwrap();
+        let transactions_executed_total = IntCounterVec::new(Opts::new("transactions_executed_total", "Executed transactions by type and status"), &["type", "status"]).unwrap();
+        let token_total_supply = IntGaugeVec::new(Opts::new("token_total_supply", "Total supply of every fungible token"), &["token_address", "symbol"]).unwrap();
+
+        registry.register(Box::new(http_requests_total.clone())).unwrap();
+        registry.register(Box::new(http_request_duration_seconds.clone())).unwrap();
+        registry.register(Box::new(db_pool_connections.clone())).unwrap();
+        registry.register(Box::new(mempool_depth.clone())).unwrap();
+        registry.register(Box::new(block_height.clone())).unwrap();
+        registry.register(Box::new(block_production_seconds.clone())).unwrap();
+        registry.register(Box::new(transactions_executed_total.clone())).unwrap();
+        registry.register(Box::new(token_total_supply.clone())).unwrap();
+
+        PrometheusMetricsServiceImpl {
+            registry,
+            http_requests_total,
+            http_request_duration_seconds,
+            db_pool_connections,
+            mempool_depth,
+            block_height,
+            block_production_seconds,
+            transactions_executed_total,
+            token_total_supply,
+            pool,
+            chain_service,
+            transaction_repository,
+            fungible_token_service,
+        }
+    }
+
+    async fn refresh_gauges(&self) -> Result<(), CommonError> {
+        if let Some(pool) = &self.pool {
+            let state = pool.state();
+            self.db_pool_connections.with_label_values(&["max"]).set(pool.max_size() as i64);
+            self.db_pool_connections.with_label_values(&["open"]).set(state.connections as i64);
+            self.db_pool_connections.with_label_values(&["idle"]).set(state.idle_connections as i64);
+            self.db_pool_connections.with_label_values(&["in_use"]).set((state.connections - state.idle_connections) as i64);
+        }
+
+        let mempool_depth = self.transaction_repository.count_pending(None).await.map_err(|e| -> CommonError { e.into() })?;
+        self.mempool_depth.set(mempool_depth);
+        self.block_height.set(self.chain_service.info().await?.block_height as i64);
+
+        let mut offset = 0;
+        loop {
+            let params = FungibleTokenQueryParams {
+                limit: Some(TOKEN_PAGE_SIZE),
+                offset: Some(offset),
+                name_prefix: None,
+            };
+            let tokens = self.fungible_token_service.list(params).await?;
+            for token in &tokens.items {
+                self.token_total_supply.with_label_values(&[&token.address, &token.symbol]).set(token.total_supply);
+            }
+            if (tokens.items.len() as i64) < TOKEN_PAGE_SIZE {
+                break;
+            }
+            offset += TOKEN_PAGE_SIZE;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl MetricsService for PrometheusMetricsServiceImpl {
+    fn observe_http_request(&self, method: &str, route: &str, status: u16, seconds: f64) {
+        self.http_requests_total.with_label_values(&[method, route, &status.to_string()]).inc();
+        self.http_request_duration_seconds.with_label_values(&[method, route]).observe(seconds);
+    }
+
+    fn observe_block_production(&self, seconds: f64) {
+        self.block_production_seconds.observe(seconds);
+    }
+
+    fn record_transaction_executed(&self, transaction_type: &str, status: &str) {
+        self.transactions_executed_total.with_label_values(&[transaction_type, status]).inc();
+    }
+
+    async fn render(&self) -> Result<String, CommonError> {
+        self.refresh_gauges().await?;
+
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer).map_err(|e| CommonError {
+            message: format!("Could not encode metrics: {}", e),
+            code: 6,
+        })?;
+        Ok(String::from_utf8(buffer).unwrap())
+    }
+}
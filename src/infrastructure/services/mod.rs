@@ -1 +1,4 @@
+pub mod in_memory;
+pub mod metrics;
+pub mod schema;
 pub mod service_context;
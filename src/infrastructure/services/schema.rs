@@ -0,0 +1,117 @@
+use async_trait::async_trait;
+use std::time::Duration;
+
+use crate::domain::error::CommonError;
+use crate::domain::models::schema::SchemaStatus;
+use crate::domain::services::schema::SchemaService;
+use crate::infrastructure::databases::connection::MultiBackend;
+use crate::infrastructure::databases::connection::{DBConn, DbConnection};
+use crate::infrastructure::databases::migrations::{POSTGRESQL_MIGRATIONS, SQLITE_MIGRATIONS};
+use crate::infrastructure::error::{run, BlockingError};
+use diesel::migration::{MigrationSource, MigrationVersion};
+use diesel::r2d2::R2D2Connection;
+use diesel_migrations::{EmbeddedMigrations, MigrationHarness};
+use log::info;
+use std::sync::Arc;
+
+// The probes give up quickly on an unreachable database instead of waiting for the pool's 30 seconds timeout
+const PROBE_CONNECTION_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Clone)]
+pub struct SchemaServiceImpl {
+    pub pool: Arc<DBConn>,
+}
+
+impl SchemaServiceImpl {
+    pub fn new(db: Arc<DBConn>) -> Self {
+        SchemaServiceImpl { pool: db }
+    }
+
+    // Each backend has its own migration directory
+    fn embedded_migrations(conn: &DbConnection) -> EmbeddedMigrations {
+        match conn {
+            DbConnection::Postgresql(_) => POSTGRESQL_MIGRATIONS,
+            DbConnection::Sqlite(_) => SQLITE_MIGRATIONS,
+        }
+    }
+
+    fn status_of(conn: &mut DbConnection) -> Result<SchemaStatus, CommonError> {
+        let migrations = Self::embedded_migrations(conn);
+        let known_versions: Vec<MigrationVersion<'static>> = MigrationSource::<MultiBackend>::migrations(&migrations)
+            .map_err(schema_error)?
+            .iter()
+            .map(|migration| migration.name().version().as_owned())
+            .collect();
+        let applied_versions = conn.applied_migrations().map_err(schema_error)?;
+
+        // A migration unknown to this binary means the database was migrated by a newer release
+        if let Some(unknown_version) = applied_versions.iter().find(|version| !known_versions.contains(version)) {
+            return Err(CommonError {
+                message: format!("Database schema is ahead of this binary: migration {} is not known to it", unknown_version),
+                code: 5,
+            });
+        }
+
+        Ok(SchemaStatus {
+            version: applied_versions.iter().max().map(|version| version.to_string()),
+            applied_migrations: applied_versions.len(),
+            pending_migrations: known_versions.len() - applied_versions.len(),
+        })
+    }
+}
+
+fn schema_error(error: impl std::fmt::Display) -> CommonError {
+    CommonError {
+        message: format!("Could not read the database schema: {}", error),
+        code: 5,
+    }
+}
+
+fn unreachable_database(error: impl std::fmt::Display) -> CommonError {
+    CommonError {
+        message: format!("Could not reach the database: {}", error),
+        code: 5,
+    }
+}
+
+// The probes run on the blocking thread pool, so an unresponsive database never stalls a worker
+fn blocking_error(error: BlockingError<CommonError>) -> CommonError {
+    match error {
+        BlockingError::Error(error) => error,
+        BlockingError::Canceled => unreachable_database("the check was canceled"),
+    }
+}
+
+#[async_trait]
+impl SchemaService for SchemaServiceImpl {
+    async fn check_connection(&self) -> Result<(), CommonError> {
+        let pool = self.pool.clone();
+        run(move || {
+            let mut conn = pool.get_timeout(PROBE_CONNECTION_TIMEOUT).map_err(unreachable_database)?;
+            conn.ping().map_err(unreachable_database)
+        })
+        .await
+        .map_err(blocking_error)
+    }
+
+    async fn status(&self) -> Result<SchemaStatus, CommonError> {
+        let pool = self.pool.clone();
+        run(move || {
+            let mut conn = pool.get_timeout(PROBE_CONNECTION_TIMEOUT).map_err(schema_error)?;
+            Self::status_of(&mut conn)
+        })
+        .await
+        .map_err(blocking_error)
+    }
+
+    fn migrate(&self) -> Result<SchemaStatus, CommonError> {
+        let mut conn = self.pool.get().map_err(schema_error)?;
+        let status = Self::status_of(&mut conn)?;
+        if status.pending_migrations > 0 {
+            info!("Applying {} pending migrations...", status.pending_migrations);
+            let migrations = Self::embedded_migrations(&conn);
+            conn.run_pending_migrations(migrations).map_err(schema_error)?;
+        }
+        Self::status_of(&mut conn)
+    }
+}
@@ -1,6 +1,6 @@
 use crate::domain::models::service_context::ServiceContext;
 use crate::domain::services::service_context::ServiceContextService;
-use crate::infrastructure::databases::postgresql::DBConn;
+use crate::infrastructure::databases::connection::DBConn;
 use crate::infrastructure::models::service_context::ServiceContextDiesel;
 use diesel::prelude::*;
 use diesel::result::Error;
@@ -20,8 +20,7 @@ impl ServiceContextServiceImpl {
 
     fn get_service_context(&self) -> ServiceContext {
         use crate::infrastructure::schema::service_contexts::dsl::{id, service_contexts};
-        let mut conn = self.pool.get().unwrap();
-        let result: Result<ServiceContextDiesel, Error> = service_contexts.filter(id.eq(1)).first::<ServiceContextDiesel>(&mut conn);
+        let result: Result<ServiceContextDiesel, Error> = service_contexts.filter(id.eq(1)).first::<ServiceContextDiesel>(&mut self.pool.get().unwrap()); // Release the connection before creating the context
 
         if result.is_err() {
             info!("Service context does not exist, creating a service context...");
@@ -0,0 +1,70 @@
+use std::env;
+
+use dotenv::dotenv;
+use tracing::Subscriber;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, EnvFilter, Layer};
+
+use crate::domain::constants::{LOG_FORMAT, OTLP_ENDPOINT};
+
+// Repository spans are recorded at debug level, RUST_LOG=info,rustychain=debug shows them too
+pub const DEFAULT_LOG_FILTER: &str = "info";
+pub const SERVICE_NAME: &str = "rustychain";
+
+// RUST_LOG filters the output, LOG_FORMAT=json switches to one JSON object per line and
+// OTEL_EXPORTER_OTLP_ENDPOINT exports the spans to a collector when built with the otlp feature
+pub fn init_telemetry() {
+    dotenv().ok();
+    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(DEFAULT_LOG_FILTER));
+    let json_output = env::var(LOG_FORMAT).map(|format| format.eq_ignore_ascii_case("json")).unwrap_or(false);
+    // Closing a span logs its duration, which also gives one access log line per HTTP request
+    let fmt_layer = if json_output {
+        fmt::layer().json().with_current_span(true).with_span_list(false).with_span_events(FmtSpan::CLOSE).boxed()
+    } else {
+        fmt::layer().with_span_events(FmtSpan::CLOSE).boxed()
+    };
+
+    // Installing the subscriber also forwards the records of the log crate to it
+    tracing_subscriber::registry().with(env_filter).with(fmt_layer).with(otlp_layer()).init();
+}
+
+#[cfg(feature = "otlp")]
+fn otlp_layer<S>() -> Option<Box<dyn Layer<S> + Send + Sync>>
+where
+    S: Subscriber + Send + Sync + for<'span> LookupSpan<'span>,
+{
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry::KeyValue;
+    use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+    use opentelemetry_sdk::{runtime, trace::TracerProvider, Resource};
+
+    let endpoint = env::var(OTLP_ENDPOINT).ok()?;
+    let exporter = SpanExporter::builder().with_tonic().with_endpoint(endpoint).build().expect("Failed to create the OTLP exporter");
+    let provider = TracerProvider::builder()
+        .with_batch_exporter(exporter, runtime::Tokio)
+        .with_resource(Resource::new(vec![KeyValue::new("service.name", SERVICE_NAME)]))
+        .build();
+    let tracer = provider.tracer(SERVICE_NAME);
+    opentelemetry::global::set_tracer_provider(provider);
+    Some(tracing_opentelemetry::layer().with_tracer(tracer).boxed())
+}
+
+#[cfg(not(feature = "otlp"))]
+fn otlp_layer<S>() -> Option<Box<dyn Layer<S> + Send + Sync>>
+where
+    S: Subscriber + Send + Sync + for<'span> LookupSpan<'span>,
+{
+    if env::var(OTLP_ENDPOINT).is_ok() {
+        eprintln!("{} is set but rustychain was built without the otlp feature, spans are not exported", OTLP_ENDPOINT);
+    }
+    None
+}
+
+// Flushes the spans that are still buffered by the exporter
+pub fn shutdown_telemetry() {
+    #[cfg(feature = "otlp")]
+    opentelemetry::global::shutdown_tracer_provider();
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::from_over_into)]
+
 pub mod api;
 pub mod container;
 pub mod create_app;
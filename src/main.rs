@@ -1,15 +1,33 @@
 use actix_web::HttpServer;
-use env_logger::Env;
+use log::{info, warn};
+use rustychain::container::Container;
 use rustychain::create_app::create_app;
+use rustychain::infrastructure::databases::migrations::migrations_enabled;
+use rustychain::infrastructure::telemetry::{init_telemetry, shutdown_telemetry};
+use std::sync::Arc;
 
 #[cfg(test)]
 mod tests;
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
-    env_logger::init_from_env(Env::default().default_filter_or("debug"));
-    let server = HttpServer::new(move || create_app())
+    init_telemetry();
+    let container = Arc::new(Container::new());
+    let schema_status = if migrations_enabled() {
+        container.schema_service.migrate()
+    } else {
+        container.schema_service.status().await
+    }
+    .unwrap_or_else(|e| panic!("Failed to check the database schema: {}", e.message));
+    if schema_status.pending_migrations > 0 {
+        warn!("{} migrations are pending and were not applied", schema_status.pending_migrations);
+    }
+    info!("Database schema version: {}", schema_status.version.unwrap_or_else(|| "none".to_string()));
+    container.chain_service.init_genesis().await.expect("Failed to initialize genesis block");
+    let server = HttpServer::new(move || create_app(container.clone()))
         // .workers(4)
         .bind(("127.0.0.1", 8080))?;
-    server.run().await
+    let result = server.run().await;
+    shutdown_telemetry();
+    result
 }
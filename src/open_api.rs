@@ -1,28 +1,54 @@
 use utoipa::OpenApi;
 
 use crate::api::dto::block::{BlockDTO, MineBlockDTO};
-use crate::api::dto::fungible_token::FungibleTokenDTO;
+use crate::api::dto::chain::ChainInfoDTO;
+use crate::api::dto::fungible_token::{FungibleTokenDTO, OwnershipChangeDTO};
+use crate::api::dto::health::{HealthCheckDTO, LivenessDTO, NodeInfoDTO, ReadinessDTO};
+use crate::api::dto::rpc::{JsonRpcErrorDTO, JsonRpcRequestDTO, JsonRpcResponseDTO};
 use crate::api::dto::service_context::ServiceContextDTO;
-use crate::api::dto::transaction::{CreateTransactionDTO, TransactionDTO};
+use crate::api::dto::subscription::{LedgerEventDTO, SubscriptionMessageDTO, SubscriptionRequestDTO};
+use crate::api::dto::transaction::{
+    AdministerFtTransactionDTO, BalanceDeltaDTO, BurnFtTransactionDTO, CancelTransactionDTO, ClaimVestedFtTransactionDTO, CreateTransactionDTO, InitFtTransactionDTO, MintFtTransactionDTO,
+    MultiTransferFtTransactionDTO, SimulatedEventDTO, TransactionBatchItemDTO, TransactionBatchResultDTO, TransactionDTO, TransactionSimulationDTO, TransferFtTransactionDTO,
+    VestingTransferFtTransactionDTO,
+};
+use crate::api::dto::vesting::VestingScheduleDTO;
 use crate::api::dto::wallet::WalletDTO;
+use crate::domain::models::transaction_type::{AdministerFt, BurnFt, ClaimVestedFt, InitFt, MintFt, MultiTransferFt, Transfer, TransferFt, VestingTransferFt};
 
 use crate::api::controllers::block_handler::{__path_create_block_handler, __path_get_block_handler, __path_list_block_handler};
-use crate::api::controllers::fungible_token::{__path_get_ft_handler, __path_list_ft_handler};
+use crate::api::controllers::chain_handler::__path_get_chain_handler;
+use crate::api::controllers::fungible_token::{__path_get_ft_by_symbol_handler, __path_get_ft_handler, __path_get_ft_ownership_history_handler, __path_list_ft_handler};
+use crate::api::controllers::graphql_handler::__path_graphql_handler;
+use crate::api::controllers::health_handler::{__path_info_handler, __path_liveness_handler, __path_readiness_handler};
+use crate::api::controllers::metrics_handler::__path_metrics_handler;
+use crate::api::controllers::rpc_handler::__path_rpc_handler;
 use crate::api::controllers::service_context_handler::{__path_get_service_context_handler, __path_update_service_context_handler};
-use crate::api::controllers::transaction_handler::{__path_create_transaction_handler, __path_get_transaction_handler, __path_list_transaction_handler};
+use crate::api::controllers::stream_handler::{__path_block_stream_handler, __path_transaction_stream_handler};
+use crate::api::controllers::subscription_handler::__path_subscription_handler;
+use crate::api::controllers::transaction_handler::{
+    __path_cancel_transaction_handler, __path_create_transaction_batch_handler, __path_create_transaction_handler, __path_get_transaction_handler, __path_list_transaction_handler,
+    __path_simulate_transaction_handler,
+};
+use crate::api::controllers::vesting_handler::__path_get_account_vesting_handler;
 use crate::api::controllers::wallet_handler::{__path_get_wallet_handler, __path_list_wallet_handler};
 
 #[derive(OpenApi)]
 #[openapi(
         paths(
             get_service_context_handler, update_service_context_handler,
-            create_transaction_handler, list_transaction_handler, get_transaction_handler,
+            create_transaction_handler, create_transaction_batch_handler, simulate_transaction_handler, list_transaction_handler, get_transaction_handler, cancel_transaction_handler,
             create_block_handler, get_block_handler, list_block_handler,
             get_wallet_handler, list_wallet_handler,
-            get_ft_handler, list_ft_handler,
+            get_account_vesting_handler,
+            get_ft_handler, list_ft_handler, get_ft_by_symbol_handler, get_ft_ownership_history_handler,
+            get_chain_handler,
+            subscription_handler, block_stream_handler, transaction_stream_handler,
+            rpc_handler, graphql_handler,
+            liveness_handler, readiness_handler, info_handler, metrics_handler,
             ),
         components(
-            schemas(CreateTransactionDTO, TransactionDTO, MineBlockDTO, BlockDTO, FungibleTokenDTO, ServiceContextDTO, WalletDTO)
+            schemas(CreateTransactionDTO, InitFtTransactionDTO, MintFtTransactionDTO, BurnFtTransactionDTO, TransferFtTransactionDTO, MultiTransferFtTransactionDTO, AdministerFtTransactionDTO, VestingTransferFtTransactionDTO, ClaimVestedFtTransactionDTO, InitFt, MintFt, BurnFt, TransferFt, MultiTransferFt, Transfer, AdministerFt, VestingTransferFt, ClaimVestedFt, CancelTransactionDTO, TransactionDTO, TransactionBatchResultDTO, TransactionBatchItemDTO, TransactionSimulationDTO, BalanceDeltaDTO, SimulatedEventDTO, MineBlockDTO, BlockDTO, FungibleTokenDTO, OwnershipChangeDTO, ServiceContextDTO, WalletDTO, VestingScheduleDTO, ChainInfoDTO, LivenessDTO, HealthCheckDTO, ReadinessDTO, NodeInfoDTO, SubscriptionRequestDTO, SubscriptionMessageDTO, LedgerEventDTO, JsonRpcRequestDTO, JsonRpcResponseDTO, JsonRpcErrorDTO)
         ),
         tags(
             (name = "Rusty-Chain", description = "Rusty Chain management endpoints.")
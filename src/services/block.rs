@@ -1,79 +1,149 @@
 use chrono::prelude::*;
+use std::sync::atomic::{AtomicI32, Ordering};
 use std::sync::Arc;
+use std::time::Instant;
 
 use async_trait::async_trait;
+use tokio::sync::OnceCell;
 
+use crate::domain::constants::{GENESIS_BLOCK_NUMBER, MAX_TRANSACTIONS_PER_BLOCK};
 use crate::domain::error::CommonError;
+use crate::domain::models::address::Address;
 use crate::domain::models::block::{Block, CreateBlock};
+use crate::domain::models::ledger_event::LedgerEvent;
 use crate::domain::repositories::block::{BlockQueryParams, BlockRepository};
 use crate::domain::repositories::repository::ResultPaging;
-use crate::domain::repositories::transaction::TransactionQueryParams;
 use crate::domain::services::block::BlockService;
+use crate::domain::services::chain::ChainService;
+use crate::domain::services::event_bus::EventBus;
+use crate::domain::services::mempool::MempoolService;
+use crate::domain::services::metrics::MetricsService;
 use crate::domain::services::transaction::TransactionService;
 use crate::domain::services::wallet::WalletService;
 use crate::utils::hex_utils::generate_block_hash;
+use tracing::instrument;
 
 #[derive(Clone)]
 pub struct BlockServiceImpl {
     pub repository: Arc<dyn BlockRepository>,
     pub transaction_service: Arc<dyn TransactionService>,
+    pub mempool_service: Arc<dyn MempoolService>,
     pub wallet_service: Arc<dyn WalletService>,
+    pub chain_service: Arc<dyn ChainService>,
+    pub metrics_service: Arc<dyn MetricsService>,
+    pub event_bus: Arc<dyn EventBus>,
+    // Highest block whose events were all published, a block is stored before its transactions run
+    published_height: Arc<OnceCell<AtomicI32>>,
 }
 
 impl BlockServiceImpl {
-    pub fn new(repository: Arc<dyn BlockRepository>, transaction_service: Arc<dyn TransactionService>, wallet_service: Arc<dyn WalletService>) -> Self {
+    pub fn new(
+        repository: Arc<dyn BlockRepository>,
+        transaction_service: Arc<dyn TransactionService>,
+        mempool_service: Arc<dyn MempoolService>,
+        wallet_service: Arc<dyn WalletService>,
+        chain_service: Arc<dyn ChainService>,
+        metrics_service: Arc<dyn MetricsService>,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
         BlockServiceImpl {
             repository,
             transaction_service,
+            mempool_service,
             wallet_service,
+            chain_service,
+            metrics_service,
+            event_bus,
+            published_height: Arc::new(OnceCell::new()),
         }
     }
+
+    // Read from storage the first time, every block stored before this node produced one had its events published
+    async fn published_height(&self) -> Result<&AtomicI32, CommonError> {
+        self.published_height
+            .get_or_try_init(|| async {
+                let latest_block = self.list(BlockQueryParams { limit: Some(1), offset: Some(0) }).await?;
+                let latest_block_number = latest_block.items.first().map(|b| b.block_number).unwrap_or(GENESIS_BLOCK_NUMBER - 1);
+                Ok::<AtomicI32, CommonError>(AtomicI32::new(latest_block_number))
+            })
+            .await
+    }
 }
 
 #[async_trait]
 impl BlockService for BlockServiceImpl {
-    async fn create(&self, miner_address: &str) -> Result<Block, CommonError> {
-        let transaction_query_params = TransactionQueryParams {
-            limit: Some(2),
-            offset: Some(0),
-            is_mined: Some(false),
-        };
+    #[instrument(name = "block_service.create", skip_all, fields(miner_address = %miner_address))]
+    async fn create(&self, miner_address: &Address) -> Result<Block, CommonError> {
+        if !self.chain_service.is_authority(miner_address) {
+            return Err(CommonError {
+                message: format!("{} is not an authority of this chain", miner_address),
+                code: 4,
+            });
+        }
 
+        let started_at = Instant::now();
         let block_query_params = BlockQueryParams { limit: Some(1), offset: Some(0) };
 
-        let raw_transactions = self.transaction_service.list(transaction_query_params).await?;
+        let raw_transactions = self.mempool_service.select(MAX_TRANSACTIONS_PER_BLOCK).await?;
 
         let parent_block = self.list(block_query_params).await?; // Get last mined block
+        let parent_block = parent_block.items.first().ok_or(CommonError {
+            message: String::from("Genesis block has not been initialized"),
+            code: 4,
+        })?;
 
-        let mut new_block = CreateBlock {
+        let new_block = CreateBlock {
+            block_number: None,
             block_hash: generate_block_hash(),
-            parent_hash: if parent_block.items.is_empty() {
-                "0x000000000000000000000000000000000000000000000000000000000GENESIS".to_string()
-            } else {
-                parent_block.items[0].block_hash.clone()
-            },
-            miner_address: miner_address.to_string(),
+            parent_hash: parent_block.block_hash.clone(),
+            miner_address: miner_address.clone(),
             timestamp: Some(Utc::now().naive_utc()),
-            transaction_count: raw_transactions.items.len() as i32,
+            transaction_count: raw_transactions.len() as i32,
         };
 
-        let created_block = self.repository.create(&mut new_block).await.map_err(|e| -> CommonError { e.into() })?;
+        // Read before the new block is stored, so it never counts as published
+        self.published_height().await?;
+        let created_block = self.repository.create(&new_block).await.map_err(|e| -> CommonError { e.into() })?;
 
-        // Update the transactions
-        for txn in raw_transactions.items {
-            self.transaction_service.execute(created_block.block_number, &txn).await?;
+        // Update the transactions, skipping the ones cancelled or expired since they were selected
+        let mut receipts = Vec::with_capacity(raw_transactions.len());
+        for txn in raw_transactions {
+            if let Some(receipt) = self.transaction_service.execute(created_block.block_number, &txn).await? {
+                receipts.push(receipt);
+            }
         }
+        self.metrics_service.observe_block_production(started_at.elapsed().as_secs_f64());
+
+        // Notify subscribers once the whole block is committed
+        self.event_bus.publish(LedgerEvent::NewBlock(created_block.clone()));
+        for receipt in receipts {
+            self.event_bus.publish(LedgerEvent::TransactionStatus(receipt.transaction));
+            for wallet in receipt.updated_wallets {
+                self.event_bus.publish(LedgerEvent::WalletChanged(wallet));
+            }
+        }
+        self.published_height().await?.fetch_max(created_block.block_number, Ordering::SeqCst);
         Ok(created_block)
     }
 
+    #[instrument(name = "block_service.list", skip_all, fields(params = ?params))]
     async fn list(&self, params: BlockQueryParams) -> Result<ResultPaging<Block>, CommonError> {
         self.repository.list(params).await.map_err(|e| -> CommonError { e.into() })
     }
 
+    #[instrument(name = "block_service.list_after", skip_all, fields(block_number, limit))]
+    async fn list_after(&self, block_number: i32, limit: i64) -> Result<Vec<Block>, CommonError> {
+        let published_height = self.published_height().await?.load(Ordering::SeqCst);
+        let blocks = self.repository.list_after(block_number, limit).await.map_err(|e| -> CommonError { e.into() })?;
+        Ok(blocks.into_iter().take_while(|b| b.block_number <= published_height).collect())
+    }
+
+    #[instrument(name = "block_service.get", skip_all, fields(block_number))]
     async fn get(&self, block_number: i32) -> Result<Block, CommonError> {
         self.repository.get(block_number).await.map_err(|e| -> CommonError { e.into() })
     }
 
+    #[instrument(name = "block_service.delete", skip_all, fields(block_number))]
     async fn delete(&self, block_number: i32) -> Result<(), CommonError> {
         self.repository.delete(block_number).await.map_err(|e| -> CommonError { e.into() })
     }
@@ -0,0 +1,184 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::DateTime;
+use log::info;
+
+use crate::domain::constants::{GENESIS_BLOCK_NUMBER, GENESIS_PARENT_HASH};
+use crate::domain::error::CommonError;
+use crate::domain::models::address::Address;
+use crate::domain::models::block::{Block, CreateBlock};
+use crate::domain::models::chain::ChainInfo;
+use crate::domain::models::fungible_token::FungibleToken;
+use crate::domain::models::genesis::Genesis;
+use crate::domain::models::ledger::LedgerChanges;
+use crate::domain::models::wallet::Wallet;
+use crate::domain::repositories::block::{BlockQueryParams, BlockRepository};
+use crate::domain::services::chain::ChainService;
+use crate::domain::services::ledger::LedgerService;
+use crate::domain::services::schema::SchemaService;
+use crate::utils::hex_utils::keccak256_hex;
+use tracing::instrument;
+
+#[derive(Clone)]
+pub struct ChainServiceImpl {
+    pub genesis: Genesis,
+    pub genesis_hash: String,
+    pub block_repository: Arc<dyn BlockRepository>,
+    pub schema_service: Arc<dyn SchemaService>,
+    pub ledger_service: Arc<dyn LedgerService>,
+}
+
+impl ChainServiceImpl {
+    pub fn new(genesis: Genesis, block_repository: Arc<dyn BlockRepository>, schema_service: Arc<dyn SchemaService>, ledger_service: Arc<dyn LedgerService>) -> Self {
+        ChainServiceImpl {
+            genesis_hash: genesis_hash(&genesis),
+            genesis,
+            block_repository,
+            schema_service,
+            ledger_service,
+        }
+    }
+
+    // Should check that every balance belongs to a declared token and no token is declared twice
+    fn validate_genesis(&self) -> Result<(), CommonError> {
+        let mut error_messages = String::new();
+        let mut token_addresses = HashSet::new();
+
+        for token in &self.genesis.fungible_tokens {
+            if !token_addresses.insert(token.address.as_str()) {
+                error_messages += &format!("Token {} is declared more than once. ", token.address);
+            }
+        }
+
+        for balance in &self.genesis.balances {
+            if !token_addresses.contains(balance.token_address.as_str()) {
+                error_messages += &format!("Balance of {} refers to undeclared token {}. ", balance.address, balance.token_address);
+            }
+            if balance.balance < 0 {
+                error_messages += &format!("Balance of {} must not be negative. ", balance.address);
+            }
+        }
+
+        if error_messages.is_empty() {
+            Ok(())
+        } else {
+            Err(CommonError {
+                message: format!("Invalid genesis: {}", error_messages),
+                code: 4,
+            })
+        }
+    }
+
+    async fn apply_genesis(&self) -> Result<Block, CommonError> {
+        self.validate_genesis()?;
+
+        // Step-1 : create the pre-allocated tokens, their supply is the sum of the initial balances
+        let created_tokens = self
+            .genesis
+            .fungible_tokens
+            .iter()
+            .map(|token| FungibleToken {
+                address: token.address.clone(),
+                symbol: token.symbol.clone(),
+                name: token.name.clone(),
+                owner_address: token.owner_address.clone(),
+                decimals: token.decimals,
+                total_supply: self.genesis.balances.iter().filter(|b| b.token_address == token.address).map(|b| b.balance).sum(),
+                block_number: GENESIS_BLOCK_NUMBER,
+                transaction_hash: self.genesis_hash.clone(),
+                minter_addresses: vec![],
+                max_supply: None,
+                mintable: true,
+                burnable: true,
+                paused: false,
+            })
+            .collect();
+
+        // Step-2 : credit the initial balances
+        let wallets = self
+            .genesis
+            .balances
+            .iter()
+            .map(|balance| Wallet {
+                address: balance.address.clone(),
+                token_address: balance.token_address.clone(),
+                balance: balance.balance,
+                block_number: GENESIS_BLOCK_NUMBER,
+                transaction_hash: self.genesis_hash.clone(),
+                frozen: false,
+            })
+            .collect();
+
+        // Step-3 : commit block 0 together with its state, a node interrupted half-way would otherwise find a partial genesis on restart
+        let genesis_block = CreateBlock {
+            block_number: Some(GENESIS_BLOCK_NUMBER),
+            block_hash: self.genesis_hash.clone(),
+            parent_hash: GENESIS_PARENT_HASH.to_string(),
+            timestamp: Some(self.genesis.timestamp.unwrap_or(DateTime::UNIX_EPOCH.naive_utc())),
+            miner_address: Address::zero(),
+            transaction_count: 0,
+        };
+        self.ledger_service
+            .commit(LedgerChanges {
+                block: Some(genesis_block),
+                created_tokens,
+                wallets,
+                ..Default::default()
+            })
+            .await?;
+        self.block_repository.get(GENESIS_BLOCK_NUMBER).await.map_err(|e| -> CommonError { e.into() })
+    }
+}
+
+// The genesis hash is derived from the genesis definition itself, so nodes started from the same file agree on the chain identifier
+pub fn genesis_hash(genesis: &Genesis) -> String {
+    let encoded = serde_json::to_vec(genesis).expect("Failed to serialize genesis");
+    keccak256_hex(&encoded)
+}
+
+#[async_trait]
+impl ChainService for ChainServiceImpl {
+    #[instrument(name = "chain_service.init_genesis", skip_all, fields(chain_id = %self.genesis.chain_id))]
+    async fn init_genesis(&self) -> Result<Block, CommonError> {
+        if let Ok(existing_block) = self.block_repository.get(GENESIS_BLOCK_NUMBER).await {
+            if existing_block.block_hash != self.genesis_hash {
+                return Err(CommonError {
+                    message: format!(
+                        "Genesis mismatch: chain was initialized with {} but the configured genesis hashes to {}",
+                        existing_block.block_hash, self.genesis_hash
+                    ),
+                    code: 4,
+                });
+            }
+            return Ok(existing_block);
+        }
+
+        info!("Applying genesis for chain {}...", self.genesis.chain_id);
+        self.apply_genesis().await
+    }
+
+    #[instrument(name = "chain_service.info", skip_all)]
+    async fn info(&self) -> Result<ChainInfo, CommonError> {
+        let latest_block = self
+            .block_repository
+            .list(BlockQueryParams { limit: Some(1), offset: Some(0) })
+            .await
+            .map_err(|e| -> CommonError { e.into() })?;
+        let schema_status = self.schema_service.status().await?;
+
+        Ok(ChainInfo {
+            chain_id: self.genesis.chain_id.clone(),
+            genesis_hash: self.genesis_hash.clone(),
+            authorities: self.genesis.authorities.clone(),
+            block_height: latest_block.items.first().map(|b| b.block_number).unwrap_or_default(),
+            schema_version: schema_status.version,
+        })
+    }
+
+    fn is_authority(&self, address: &str) -> bool {
+        // A chain without configured authorities accepts blocks from any miner
+        self.genesis.authorities.is_empty() || self.genesis.authorities.iter().any(|a| a == address)
+    }
+}
@@ -0,0 +1,35 @@
+use tokio::sync::broadcast::{self, Receiver, Sender};
+
+use crate::domain::models::ledger_event::LedgerEvent;
+use crate::domain::services::event_bus::EventBus;
+
+// Subscribers falling more than EVENT_BUS_CAPACITY events behind are told they lagged and skip ahead
+pub const EVENT_BUS_CAPACITY: usize = 1024;
+
+pub struct BroadcastEventBus {
+    sender: Sender<LedgerEvent>,
+}
+
+impl BroadcastEventBus {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        BroadcastEventBus { sender }
+    }
+}
+
+impl Default for BroadcastEventBus {
+    fn default() -> Self {
+        Self::new(EVENT_BUS_CAPACITY)
+    }
+}
+
+impl EventBus for BroadcastEventBus {
+    fn publish(&self, event: LedgerEvent) {
+        // Sending only fails when nobody is subscribed, in which case the event is simply dropped
+        let _ = self.sender.send(event);
+    }
+
+    fn subscribe(&self) -> Receiver<LedgerEvent> {
+        self.sender.subscribe()
+    }
+}
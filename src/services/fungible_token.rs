@@ -1,76 +1,305 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
 
 use crate::domain::error::CommonError;
-use crate::domain::models::fungible_token::{FungibleToken, UpdatedFungibleToken};
+use crate::domain::models::address::Address;
+use crate::domain::models::fungible_token::{FungibleToken, OwnershipChange, UpdatedFungibleToken};
 use crate::domain::models::wallet::Wallet;
 use crate::domain::repositories::fungible_token::{FungibleTokenQueryParams, FungibleTokenRepository};
 use crate::domain::repositories::repository::ResultPaging;
 use crate::domain::services::fungible_token::FungibleTokenService;
+use tracing::instrument;
 
 #[derive(Clone)]
 pub struct FungibleTokenServiceImpl {
     pub repository: Arc<dyn FungibleTokenRepository>,
+    // Uppercased symbol mapped to the only issuer allowed to use it, None reserves it for nobody
+    pub reserved_symbols: HashMap<String, Option<Address>>,
 }
 
 impl FungibleTokenServiceImpl {
-    pub fn new(repository: Arc<dyn FungibleTokenRepository>) -> Self {
-        FungibleTokenServiceImpl { repository }
+    pub fn new(repository: Arc<dyn FungibleTokenRepository>, reserved_symbols: HashMap<String, Option<Address>>) -> Self {
+        FungibleTokenServiceImpl { repository, reserved_symbols }
     }
 }
 
+// Symbols are unique regardless of case
+pub fn symbol_taken(symbol: &str) -> CommonError {
+    CommonError {
+        message: format!("Symbol {} is already taken", symbol),
+        code: 2,
+    }
+}
+
+fn not_owner() -> CommonError {
+    CommonError {
+        message: String::from("Only owner can administer token"),
+        code: 2,
+    }
+}
+
+// Fails for a paused token
+pub fn check_not_paused(token: &FungibleToken) -> Result<(), CommonError> {
+    if token.paused {
+        return Err(CommonError {
+            message: String::from("Token is paused"),
+            code: 2,
+        });
+    }
+    Ok(())
+}
+
+// Fails for a frozen wallet
+pub fn check_not_frozen(wallet: &Wallet) -> Result<(), CommonError> {
+    if wallet.frozen {
+        return Err(CommonError {
+            message: format!("Wallet {} is frozen", wallet.address),
+            code: 2,
+        });
+    }
+    Ok(())
+}
+
+// Only the owner and the minters can mint, as long as the token is mintable, not paused and stays within its max supply
+pub fn minted_supply(token: &FungibleToken, requester_address: &str, amount: i64) -> Result<UpdatedFungibleToken, CommonError> {
+    check_not_paused(token)?;
+    if !token.mintable {
+        return Err(CommonError {
+            message: String::from("Token is not mintable"),
+            code: 2,
+        });
+    }
+    if !token.can_mint(requester_address) {
+        return Err(CommonError {
+            message: String::from("Only owner or minter can mint token"),
+            code: 2,
+        });
+    }
+    let total_supply = token
+        .total_supply
+        .checked_add(amount)
+        .filter(|total_supply| token.max_supply.is_none_or(|max_supply| *total_supply <= max_supply));
+    let Some(total_supply) = total_supply else {
+        return Err(CommonError {
+            message: format!("Minting {} tokens would exceed the max supply", amount),
+            code: 2,
+        });
+    };
+    Ok(UpdatedFungibleToken {
+        total_supply: Some(total_supply),
+        minter_addresses: None,
+        paused: None,
+    })
+}
+
+// Only the owner can grant the minter role
+pub fn granted_minter(token: &FungibleToken, requester_address: &str, minter_address: &Address) -> Result<UpdatedFungibleToken, CommonError> {
+    if !token.is_owner(requester_address) {
+        return Err(not_owner());
+    }
+    if token.minter_addresses.iter().any(|address| address == minter_address) {
+        return Err(CommonError {
+            message: format!("{} is already a minter", minter_address),
+            code: 2,
+        });
+    }
+    let mut minter_addresses = token.minter_addresses.clone();
+    minter_addresses.push(minter_address.clone());
+    Ok(UpdatedFungibleToken {
+        total_supply: None,
+        minter_addresses: Some(minter_addresses),
+        paused: None,
+    })
+}
+
+// Only the owner can revoke the minter role
+pub fn revoked_minter(token: &FungibleToken, requester_address: &str, minter_address: &Address) -> Result<UpdatedFungibleToken, CommonError> {
+    if !token.is_owner(requester_address) {
+        return Err(not_owner());
+    }
+    if !token.minter_addresses.iter().any(|address| address == minter_address) {
+        return Err(CommonError {
+            message: format!("{} is not a minter", minter_address),
+            code: 2,
+        });
+    }
+    Ok(UpdatedFungibleToken {
+        total_supply: None,
+        minter_addresses: Some(token.minter_addresses.iter().filter(|address| *address != minter_address).cloned().collect()),
+        paused: None,
+    })
+}
+
+// Only the owner can hand the token over, renouncing hands it to the zero address
+pub fn ownership_change(token: &FungibleToken, requester_address: &str, new_owner_address: &Address, block_number: i32, transaction_hash: &str) -> Result<OwnershipChange, CommonError> {
+    if !token.is_owner(requester_address) {
+        return Err(not_owner());
+    }
+    if *new_owner_address == token.owner_address {
+        return Err(CommonError {
+            message: String::from("New owner is already the owner"),
+            code: 2,
+        });
+    }
+    Ok(OwnershipChange {
+        token_address: token.address.clone(),
+        previous_owner_address: token.owner_address.clone(),
+        new_owner_address: new_owner_address.clone(),
+        block_number,
+        transaction_hash: transaction_hash.to_string(),
+    })
+}
+
+// Only the owner can pause or unpause the token
+pub fn paused_token(token: &FungibleToken, requester_address: &str, paused: bool) -> Result<UpdatedFungibleToken, CommonError> {
+    if !token.is_owner(requester_address) {
+        return Err(not_owner());
+    }
+    if token.paused == paused {
+        return Err(CommonError {
+            message: format!("Token is already {}", if paused { "paused" } else { "unpaused" }),
+            code: 2,
+        });
+    }
+    Ok(UpdatedFungibleToken {
+        total_supply: None,
+        minter_addresses: None,
+        paused: Some(paused),
+    })
+}
+
+// Only the owner can freeze or unfreeze a wallet of the token
+pub fn frozen_wallet(token: &FungibleToken, requester_address: &str, wallet: Wallet, frozen: bool, block_number: i32, transaction_hash: &str) -> Result<Wallet, CommonError> {
+    if !token.is_owner(requester_address) {
+        return Err(not_owner());
+    }
+    if wallet.frozen == frozen {
+        return Err(CommonError {
+            message: format!("Wallet {} is already {}", wallet.address, if frozen { "frozen" } else { "unfrozen" }),
+            code: 2,
+        });
+    }
+    Ok(Wallet {
+        frozen,
+        block_number,
+        transaction_hash: transaction_hash.to_string(),
+        ..wallet
+    })
+}
+
+// Renouncing is final, the token keeps its minters but nobody can grant or revoke the role anymore
+pub fn renounced_ownership(token: &FungibleToken, requester_address: &str, block_number: i32, transaction_hash: &str) -> Result<OwnershipChange, CommonError> {
+    ownership_change(token, requester_address, &Address::zero(), block_number, transaction_hash)
+}
+
+// The requester can only burn tokens it holds, of a burnable token that is not paused, unless its wallet is frozen
+pub fn burnt_supply(token: &FungibleToken, requester_wallet: &Wallet, amount: i64) -> Result<UpdatedFungibleToken, CommonError> {
+    check_not_paused(token)?;
+    check_not_frozen(requester_wallet)?;
+    if !token.burnable {
+        return Err(CommonError {
+            message: String::from("Token is not burnable"),
+            code: 2,
+        });
+    }
+    let pre_conditions: bool = requester_wallet.token_address == token.address && requester_wallet.balance >= amount;
+    if !pre_conditions {
+        return Err(CommonError {
+            message: String::from("Requester does not have enough balance"),
+            code: 2,
+        });
+    }
+    let pre_conditions: bool = token.total_supply >= amount;
+    if !pre_conditions {
+        return Err(CommonError {
+            message: String::from("Insufficient token balance to burn"),
+            code: 2,
+        });
+    }
+    Ok(UpdatedFungibleToken {
+        total_supply: Some(token.total_supply - amount),
+        minter_addresses: None,
+        paused: None,
+    })
+}
+
 #[async_trait]
 impl FungibleTokenService for FungibleTokenServiceImpl {
+    #[instrument(name = "fungible_token_service.create", skip_all, fields(token_address = %create_fungible_token.address))]
     async fn create(&self, create_fungible_token: FungibleToken) -> Result<FungibleToken, CommonError> {
         let created_fungible_token = self.repository.create(&create_fungible_token).await.map_err(|e| -> CommonError { e.into() })?;
 
         Ok(created_fungible_token)
     }
 
+    #[instrument(name = "fungible_token_service.get", skip_all, fields(token_address = %address))]
     async fn get(&self, address: &str) -> Result<FungibleToken, CommonError> {
         self.repository.get(address).await.map_err(|e| -> CommonError { e.into() })
     }
 
+    #[instrument(name = "fungible_token_service.find_by_symbol", skip_all, fields(symbol = %symbol))]
+    async fn find_by_symbol(&self, symbol: &str) -> Result<Option<FungibleToken>, CommonError> {
+        self.repository.find_by_symbol(symbol).await.map_err(|e| -> CommonError { e.into() })
+    }
+
+    #[instrument(name = "fungible_token_service.get_by_symbol", skip_all, fields(symbol = %symbol))]
+    async fn get_by_symbol(&self, symbol: &str) -> Result<FungibleToken, CommonError> {
+        self.find_by_symbol(symbol).await?.ok_or_else(|| CommonError {
+            message: format!("No token with symbol {}", symbol),
+            code: 1,
+        })
+    }
+
+    #[instrument(name = "fungible_token_service.check_symbol_available", skip_all, fields(symbol = %symbol))]
+    async fn check_symbol_available(&self, symbol: &str, issuer_address: &Address) -> Result<(), CommonError> {
+        if let Some(allowed_issuer) = self.reserved_symbols.get(&symbol.to_uppercase()) {
+            if allowed_issuer.as_ref() != Some(issuer_address) {
+                return Err(CommonError {
+                    message: format!("Symbol {} is reserved", symbol),
+                    code: 2,
+                });
+            }
+        }
+        if self.find_by_symbol(symbol).await?.is_some() {
+            return Err(symbol_taken(symbol));
+        }
+        Ok(())
+    }
+
+    #[instrument(name = "fungible_token_service.list", skip_all, fields(params = ?params))]
     async fn list(&self, params: FungibleTokenQueryParams) -> Result<ResultPaging<FungibleToken>, CommonError> {
         self.repository.list(params).await.map_err(|e| -> CommonError { e.into() })
     }
 
+    #[instrument(name = "fungible_token_service.update", skip_all, fields(token_address = %token_address))]
+    async fn update(&self, token_address: &str, updated_fungible_token: UpdatedFungibleToken) -> Result<FungibleToken, CommonError> {
+        self.repository.update(token_address, updated_fungible_token).await.map_err(|e| -> CommonError { e.into() })
+    }
+
+    #[instrument(name = "fungible_token_service.change_owner", skip_all, fields(token_address = %change.token_address))]
+    async fn change_owner(&self, change: OwnershipChange) -> Result<FungibleToken, CommonError> {
+        self.repository.change_owner(&change).await.map_err(|e| -> CommonError { e.into() })
+    }
+
+    #[instrument(name = "fungible_token_service.ownership_history", skip_all, fields(token_address = %token_address))]
+    async fn ownership_history(&self, token_address: &str) -> Result<Vec<OwnershipChange>, CommonError> {
+        self.get(token_address).await?;
+        self.repository.list_ownership_changes(token_address).await.map_err(|e| -> CommonError { e.into() })
+    }
+
+    #[instrument(name = "fungible_token_service.mint", skip_all, fields(token_address = %token_address, amount))]
     async fn mint(&self, token_address: &str, requester_address: &str, amount: i64) -> Result<FungibleToken, CommonError> {
         let token: FungibleToken = self.get(token_address).await?;
-        if requester_address != token.owner_address {
-            return Err(CommonError {
-                message: String::from("Only owner can mint token"),
-                code: 2,
-            });
-        }
-        let updated_token = UpdatedFungibleToken {
-            total_supply: Some(token.total_supply + amount),
-        };
-
-        self.repository.update(token_address, updated_token).await.map_err(|e| -> CommonError { e.into() })
+        let updated_token = minted_supply(&token, requester_address, amount)?;
+        self.update(token_address, updated_token).await
     }
 
+    #[instrument(name = "fungible_token_service.burn", skip_all, fields(token_address = %token_address, amount))]
     async fn burn(&self, token_address: &str, requester_wallet: &Wallet, amount: i64) -> Result<FungibleToken, CommonError> {
         let token: FungibleToken = self.get(token_address).await?;
-        let pre_conditions: bool = requester_wallet.token_address == token_address && requester_wallet.balance >= amount;
-        if !pre_conditions {
-            return Err(CommonError {
-                message: String::from("Requester does not have enough balance"),
-                code: 2,
-            });
-        }
-        let pre_conditions: bool = token.total_supply >= amount;
-        if !pre_conditions {
-            return Err(CommonError {
-                message: String::from("Insufficient token balance to burn"),
-                code: 2,
-            });
-        }
-        let updated_token = UpdatedFungibleToken {
-            total_supply: Some(token.total_supply - amount),
-        };
-
-        self.repository.update(token_address, updated_token).await.map_err(|e| -> CommonError { e.into() })
+        let updated_token = burnt_supply(&token, requester_wallet, amount)?;
+        self.update(token_address, updated_token).await
     }
 }
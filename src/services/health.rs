@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDateTime, Utc};
+
+use crate::domain::constants::{APP_VERSION, GIT_COMMIT};
+use crate::domain::error::CommonError;
+use crate::domain::models::health::{HealthCheck, HealthReport, NodeInfo};
+use crate::domain::repositories::block::BlockQueryParams;
+use crate::domain::services::block::BlockService;
+use crate::domain::services::chain::ChainService;
+use crate::domain::services::health::HealthService;
+use crate::domain::services::schema::SchemaService;
+use crate::domain::services::transaction::TransactionService;
+use tracing::instrument;
+
+#[derive(Clone)]
+pub struct HealthServiceImpl {
+    pub schema_service: Arc<dyn SchemaService>,
+    pub chain_service: Arc<dyn ChainService>,
+    pub block_service: Arc<dyn BlockService>,
+    pub transaction_service: Arc<dyn TransactionService>,
+    pub started_at: NaiveDateTime,
+    pub block_producer_max_idle: Option<Duration>,
+}
+
+impl HealthServiceImpl {
+    pub fn new(
+        schema_service: Arc<dyn SchemaService>,
+        chain_service: Arc<dyn ChainService>,
+        block_service: Arc<dyn BlockService>,
+        transaction_service: Arc<dyn TransactionService>,
+        block_producer_max_idle: Option<Duration>,
+    ) -> Self {
+        HealthServiceImpl {
+            schema_service,
+            chain_service,
+            block_service,
+            transaction_service,
+            started_at: Utc::now().naive_utc(),
+            block_producer_max_idle,
+        }
+    }
+
+    async fn check_database(&self) -> HealthCheck {
+        health_check("database", self.schema_service.check_connection().await)
+    }
+
+    async fn check_migrations(&self) -> HealthCheck {
+        let result = self.schema_service.status().await.and_then(|status| match status.pending_migrations {
+            0 => Ok(()),
+            pending => Err(CommonError {
+                message: format!("{} migrations are pending", pending),
+                code: 5,
+            }),
+        });
+        health_check("migrations", result)
+    }
+
+    // The producer is alive once the genesis block exists and, when a maximum idle time is configured, a block was mined recently
+    async fn check_block_producer(&self) -> HealthCheck {
+        let result = self.block_service.list(BlockQueryParams { limit: Some(1), offset: Some(0) }).await.and_then(|blocks| {
+            let latest_block = blocks.items.first().ok_or(CommonError {
+                message: String::from("Genesis block has not been initialized"),
+                code: 4,
+            })?;
+            let Some(max_idle) = self.block_producer_max_idle else {
+                return Ok(());
+            };
+            // A node that just started gets the whole idle window to produce its first block
+            let last_activity = latest_block.timestamp.unwrap_or_default().max(self.started_at);
+            let idle = Utc::now().naive_utc() - last_activity;
+            if idle > max_idle {
+                return Err(CommonError {
+                    message: format!("No block was produced for {} seconds", idle.num_seconds()),
+                    code: 4,
+                });
+            }
+            Ok(())
+        });
+        health_check("block_producer", result)
+    }
+}
+
+fn health_check(name: &str, result: Result<(), CommonError>) -> HealthCheck {
+    HealthCheck {
+        name: name.to_string(),
+        healthy: result.is_ok(),
+        message: result.err().map(|e| e.message),
+    }
+}
+
+#[async_trait]
+impl HealthService for HealthServiceImpl {
+    #[instrument(name = "health_service.readiness", skip_all)]
+    async fn readiness(&self) -> HealthReport {
+        let checks = vec![self.check_database().await, self.check_migrations().await, self.check_block_producer().await];
+        HealthReport {
+            healthy: checks.iter().all(|check| check.healthy),
+            checks,
+        }
+    }
+
+    #[instrument(name = "health_service.info", skip_all)]
+    async fn info(&self) -> Result<NodeInfo, CommonError> {
+        let chain_info = self.chain_service.info().await?;
+        let mempool_size = self.transaction_service.pending_count().await?;
+
+        Ok(NodeInfo {
+            version: APP_VERSION.to_string(),
+            git_commit: GIT_COMMIT.to_string(),
+            chain_id: chain_info.chain_id,
+            block_height: chain_info.block_height,
+            mempool_size,
+            schema_version: chain_info.schema_version,
+        })
+    }
+}
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::error::CommonError;
+use crate::domain::models::ledger::LedgerChanges;
+use crate::domain::repositories::ledger::LedgerRepository;
+use crate::domain::services::ledger::LedgerService;
+use tracing::instrument;
+
+#[derive(Clone)]
+pub struct LedgerServiceImpl {
+    pub repository: Arc<dyn LedgerRepository>,
+}
+
+impl LedgerServiceImpl {
+    pub fn new(repository: Arc<dyn LedgerRepository>) -> Self {
+        LedgerServiceImpl { repository }
+    }
+}
+
+#[async_trait]
+impl LedgerService for LedgerServiceImpl {
+    #[instrument(name = "ledger_service.commit", skip_all, fields(created_tokens = changes.created_tokens.len(), wallets = changes.wallets.len()))]
+    async fn commit(&self, changes: LedgerChanges) -> Result<(), CommonError> {
+        self.repository.commit(&changes).await.map_err(|e| -> CommonError { e.into() })
+    }
+}
@@ -0,0 +1,269 @@
+use async_trait::async_trait;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+
+use crate::domain::error::CommonError;
+use crate::domain::models::fungible_token::{FungibleToken, OwnershipChange, UpdatedFungibleToken};
+use crate::domain::models::ledger::LedgerChanges;
+use crate::domain::models::vesting::{CreateVestingSchedule, VestingSchedule};
+use crate::domain::models::wallet::Wallet;
+use crate::domain::services::fungible_token::FungibleTokenService;
+use crate::domain::services::vesting::VestingService;
+use crate::domain::services::wallet::WalletService;
+
+// The ledger the transaction handlers read
+#[async_trait]
+pub trait LedgerReader: Send + Sync {
+    async fn get_wallet(&self, address: &str, token_address: &str) -> Result<Wallet, CommonError>;
+    async fn get_token(&self, token_address: &str) -> Result<FungibleToken, CommonError>;
+    async fn find_token_by_symbol(&self, symbol: &str) -> Result<Option<FungibleToken>, CommonError>;
+    async fn list_vestings(&self, beneficiary_address: &str, token_address: &str) -> Result<Vec<VestingSchedule>, CommonError>;
+}
+
+// The ledger the transaction handlers read and write, so the same handlers either execute or only simulate a transaction
+#[async_trait]
+pub trait LedgerState: LedgerReader {
+    async fn put_wallets(&self, wallets: Vec<Wallet>) -> Result<Vec<Wallet>, CommonError>;
+    async fn create_token(&self, token: FungibleToken) -> Result<FungibleToken, CommonError>;
+    async fn update_token(&self, token_address: &str, updated_token: UpdatedFungibleToken) -> Result<FungibleToken, CommonError>;
+    async fn change_token_owner(&self, change: OwnershipChange) -> Result<FungibleToken, CommonError>;
+    async fn create_vesting(&self, new_schedule: CreateVestingSchedule) -> Result<VestingSchedule, CommonError>;
+    async fn update_vesting_claimed(&self, schedule: &VestingSchedule, claimed_amount: i64) -> Result<VestingSchedule, CommonError>;
+}
+
+// Reads straight from the services, the writes go through an overlay committed as a whole
+pub struct PersistentLedgerState {
+    pub wallet_service: Arc<dyn WalletService>,
+    pub fungible_token_service: Arc<dyn FungibleTokenService>,
+    pub vesting_service: Arc<dyn VestingService>,
+}
+
+impl PersistentLedgerState {
+    pub fn new(wallet_service: Arc<dyn WalletService>, fungible_token_service: Arc<dyn FungibleTokenService>, vesting_service: Arc<dyn VestingService>) -> Self {
+        PersistentLedgerState {
+            wallet_service,
+            fungible_token_service,
+            vesting_service,
+        }
+    }
+}
+
+#[async_trait]
+impl LedgerReader for PersistentLedgerState {
+    async fn get_wallet(&self, address: &str, token_address: &str) -> Result<Wallet, CommonError> {
+        self.wallet_service.get(address, token_address).await
+    }
+
+    async fn get_token(&self, token_address: &str) -> Result<FungibleToken, CommonError> {
+        self.fungible_token_service.get(token_address).await
+    }
+
+    async fn find_token_by_symbol(&self, symbol: &str) -> Result<Option<FungibleToken>, CommonError> {
+        self.fungible_token_service.find_by_symbol(symbol).await
+    }
+
+    async fn list_vestings(&self, beneficiary_address: &str, token_address: &str) -> Result<Vec<VestingSchedule>, CommonError> {
+        self.vesting_service.list(beneficiary_address, Some(token_address)).await
+    }
+}
+
+// Reads through to the underlying state but keeps every write in memory, until its changes are committed as a whole or dropped
+pub struct LedgerStateOverlay {
+    base: Arc<dyn LedgerReader>,
+    // Keyed by (address, token_address) like the wallets table
+    wallets: Mutex<BTreeMap<(String, String), Wallet>>,
+    // The latest state of every token written, created or not
+    tokens: Mutex<BTreeMap<String, FungibleToken>>,
+    created_token_addresses: Mutex<BTreeSet<String>>,
+    token_updates: Mutex<BTreeMap<String, UpdatedFungibleToken>>,
+    ownership_changes: Mutex<Vec<OwnershipChange>>,
+    // Keyed by id, the schedules created in the overlay take negative ids
+    vesting_schedules: Mutex<BTreeMap<i32, VestingSchedule>>,
+}
+
+impl LedgerStateOverlay {
+    pub fn new(base: Arc<dyn LedgerReader>) -> Self {
+        LedgerStateOverlay {
+            base,
+            wallets: Mutex::new(BTreeMap::new()),
+            tokens: Mutex::new(BTreeMap::new()),
+            created_token_addresses: Mutex::new(BTreeSet::new()),
+            token_updates: Mutex::new(BTreeMap::new()),
+            ownership_changes: Mutex::new(Vec::new()),
+            vesting_schedules: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    // The wallets written to the overlay
+    pub fn wallets(&self) -> Vec<Wallet> {
+        self.wallets.lock().unwrap().values().cloned().collect()
+    }
+
+    // Every write of the overlay, to be committed in a single database transaction
+    pub fn changes(&self) -> LedgerChanges {
+        let tokens = self.tokens.lock().unwrap();
+        let created_token_addresses = self.created_token_addresses.lock().unwrap();
+        let vesting_schedules = self.vesting_schedules.lock().unwrap();
+        LedgerChanges {
+            created_tokens: created_token_addresses.iter().filter_map(|address| tokens.get(address).cloned()).collect(),
+            // A token created in the overlay is stored in its latest state, its updates are already part of it
+            updated_tokens: self
+                .token_updates
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(address, _)| !created_token_addresses.contains(*address))
+                .map(|(address, updated_token)| (address.clone(), updated_token.clone()))
+                .collect(),
+            ownership_changes: self.ownership_changes.lock().unwrap().clone(),
+            created_vesting_schedules: vesting_schedules.values().filter(|schedule| schedule.id < 0).map(created_vesting_schedule).collect(),
+            vesting_claims: vesting_schedules
+                .values()
+                .filter(|schedule| schedule.id > 0)
+                .map(|schedule| (schedule.id, schedule.claimed_amount))
+                .collect(),
+            wallets: self.wallets(),
+            ..Default::default()
+        }
+    }
+}
+
+// A schedule is created unclaimed, claiming it takes another transaction
+fn created_vesting_schedule(schedule: &VestingSchedule) -> CreateVestingSchedule {
+    CreateVestingSchedule {
+        token_address: schedule.token_address.clone(),
+        grantor_address: schedule.grantor_address.clone(),
+        beneficiary_address: schedule.beneficiary_address.clone(),
+        unit: schedule.unit,
+        start: schedule.start,
+        cliff: schedule.cliff,
+        duration: schedule.duration,
+        total_amount: schedule.total_amount,
+        block_number: schedule.block_number,
+        transaction_hash: schedule.transaction_hash.clone(),
+    }
+}
+
+#[async_trait]
+impl LedgerReader for LedgerStateOverlay {
+    async fn get_wallet(&self, address: &str, token_address: &str) -> Result<Wallet, CommonError> {
+        let written = self.wallets.lock().unwrap().get(&(address.to_string(), token_address.to_string())).cloned();
+        match written {
+            Some(wallet) => Ok(wallet),
+            None => self.base.get_wallet(address, token_address).await,
+        }
+    }
+
+    async fn get_token(&self, token_address: &str) -> Result<FungibleToken, CommonError> {
+        let written = self.tokens.lock().unwrap().get(token_address).cloned();
+        match written {
+            Some(token) => Ok(token),
+            None => self.base.get_token(token_address).await,
+        }
+    }
+
+    async fn find_token_by_symbol(&self, symbol: &str) -> Result<Option<FungibleToken>, CommonError> {
+        let written = self.tokens.lock().unwrap().values().find(|token| token.symbol.eq_ignore_ascii_case(symbol)).cloned();
+        match written {
+            Some(token) => Ok(Some(token)),
+            None => self.base.find_token_by_symbol(symbol).await,
+        }
+    }
+
+    async fn list_vestings(&self, beneficiary_address: &str, token_address: &str) -> Result<Vec<VestingSchedule>, CommonError> {
+        let mut schedules = self.base.list_vestings(beneficiary_address, token_address).await?;
+        let written = self.vesting_schedules.lock().unwrap();
+        for schedule in schedules.iter_mut() {
+            if let Some(written_schedule) = written.get(&schedule.id) {
+                *schedule = written_schedule.clone();
+            }
+        }
+        schedules.extend(
+            written
+                .values()
+                .filter(|s| s.id < 0 && s.beneficiary_address == beneficiary_address && s.token_address == token_address)
+                .cloned(),
+        );
+        Ok(schedules)
+    }
+}
+
+#[async_trait]
+impl LedgerState for LedgerStateOverlay {
+    async fn put_wallets(&self, wallets: Vec<Wallet>) -> Result<Vec<Wallet>, CommonError> {
+        let mut written = self.wallets.lock().unwrap();
+        for wallet in &wallets {
+            written.insert((wallet.address.to_string(), wallet.token_address.to_string()), wallet.clone());
+        }
+        Ok(wallets)
+    }
+
+    async fn create_token(&self, token: FungibleToken) -> Result<FungibleToken, CommonError> {
+        self.tokens.lock().unwrap().insert(token.address.to_string(), token.clone());
+        self.created_token_addresses.lock().unwrap().insert(token.address.to_string());
+        Ok(token)
+    }
+
+    // Successive updates of a token merge into one, the latest value of every field wins
+    async fn update_token(&self, token_address: &str, updated_token: UpdatedFungibleToken) -> Result<FungibleToken, CommonError> {
+        let mut token = self.get_token(token_address).await?;
+        {
+            let mut token_updates = self.token_updates.lock().unwrap();
+            let merged_update = token_updates.entry(token_address.to_string()).or_insert(UpdatedFungibleToken {
+                total_supply: None,
+                minter_addresses: None,
+                paused: None,
+            });
+            if let Some(total_supply) = updated_token.total_supply {
+                token.total_supply = total_supply;
+                merged_update.total_supply = Some(total_supply);
+            }
+            if let Some(minter_addresses) = updated_token.minter_addresses {
+                merged_update.minter_addresses = Some(minter_addresses.clone());
+                token.minter_addresses = minter_addresses;
+            }
+            if let Some(paused) = updated_token.paused {
+                token.paused = paused;
+                merged_update.paused = Some(paused);
+            }
+        }
+        self.tokens.lock().unwrap().insert(token_address.to_string(), token.clone());
+        Ok(token)
+    }
+
+    async fn change_token_owner(&self, change: OwnershipChange) -> Result<FungibleToken, CommonError> {
+        let mut token = self.get_token(&change.token_address).await?;
+        token.owner_address = change.new_owner_address.clone();
+        self.tokens.lock().unwrap().insert(token.address.to_string(), token.clone());
+        self.ownership_changes.lock().unwrap().push(change);
+        Ok(token)
+    }
+
+    async fn create_vesting(&self, new_schedule: CreateVestingSchedule) -> Result<VestingSchedule, CommonError> {
+        let mut written = self.vesting_schedules.lock().unwrap();
+        let id = written.keys().next().map(|first| first.min(&0) - 1).unwrap_or(-1);
+        let schedule = VestingSchedule {
+            id,
+            token_address: new_schedule.token_address,
+            grantor_address: new_schedule.grantor_address,
+            beneficiary_address: new_schedule.beneficiary_address,
+            unit: new_schedule.unit,
+            start: new_schedule.start,
+            cliff: new_schedule.cliff,
+            duration: new_schedule.duration,
+            total_amount: new_schedule.total_amount,
+            claimed_amount: 0,
+            block_number: new_schedule.block_number,
+            transaction_hash: new_schedule.transaction_hash,
+        };
+        written.insert(id, schedule.clone());
+        Ok(schedule)
+    }
+
+    async fn update_vesting_claimed(&self, schedule: &VestingSchedule, claimed_amount: i64) -> Result<VestingSchedule, CommonError> {
+        let mut updated = schedule.clone();
+        updated.claimed_amount = claimed_amount;
+        self.vesting_schedules.lock().unwrap().insert(updated.id, updated.clone());
+        Ok(updated)
+    }
+}
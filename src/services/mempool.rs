@@ -0,0 +1,216 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::domain::error::CommonError;
+use crate::domain::models::address::Address;
+use crate::domain::models::ledger_event::LedgerEvent;
+use crate::domain::models::transaction::{CreateTransaction, Transaction, TransactionStatus};
+use crate::domain::repositories::transaction::TransactionRepository;
+use crate::domain::services::event_bus::EventBus;
+use crate::domain::services::mempool::MempoolService;
+use tracing::instrument;
+
+#[derive(Clone)]
+pub struct MempoolServiceImpl {
+    pub repository: Arc<dyn TransactionRepository>,
+    pub event_bus: Arc<dyn EventBus>,
+    pub transaction_ttl: Duration,
+    pub max_pending_per_sender: i64,
+    // Held from reading the next nonces of the senders to storing the admitted transactions
+    admission_lock: Arc<Mutex<()>>,
+}
+
+// A transaction accepted by the mempool, with its resolved nonce and the pending transaction it replaces
+struct Admission {
+    transaction: CreateTransaction,
+    replaced: Option<Transaction>,
+}
+
+// What the admission of a batch knows about a sender, including the transactions of the batch admitted before
+struct SenderState {
+    next_nonce: i64,
+    pending_count: i64,
+    batch_nonces: HashSet<i64>,
+}
+
+pub fn batch_rejected() -> CommonError {
+    CommonError {
+        message: "Not submitted, another transaction of the batch is invalid".to_string(),
+        code: 3,
+    }
+}
+
+fn rejected(message: String) -> CommonError {
+    CommonError { message, code: 7 }
+}
+
+impl MempoolServiceImpl {
+    pub fn new(repository: Arc<dyn TransactionRepository>, event_bus: Arc<dyn EventBus>, transaction_ttl: Duration, max_pending_per_sender: i64) -> Self {
+        MempoolServiceImpl {
+            repository,
+            event_bus,
+            transaction_ttl,
+            max_pending_per_sender,
+            admission_lock: Arc::new(Mutex::new(())),
+        }
+    }
+
+    async fn sender_state(&self, from_address: &str) -> Result<SenderState, CommonError> {
+        let max_nonce = self.repository.max_nonce(from_address).await.map_err(|e| -> CommonError { e.into() })?;
+        let pending_count = self.repository.count_pending(Some(from_address)).await.map_err(|e| -> CommonError { e.into() })?;
+        Ok(SenderState {
+            next_nonce: max_nonce.map(|nonce| nonce + 1).unwrap_or(0),
+            pending_count,
+            batch_nonces: HashSet::new(),
+        })
+    }
+
+    async fn admit(&self, mut transaction: CreateTransaction, sender: &mut SenderState) -> Result<Admission, CommonError> {
+        let nonce = transaction.nonce.unwrap_or(sender.next_nonce);
+        if sender.batch_nonces.contains(&nonce) {
+            return Err(rejected(format!("Nonce {} is used twice in the batch", nonce)));
+        }
+
+        // Step-1 : a nonce already mined cannot be reused, a pending one only by paying a higher fee
+        let same_nonce = self.repository.list_by_nonce(&transaction.from_address, nonce).await.map_err(|e| -> CommonError { e.into() })?;
+        if same_nonce.iter().any(|t| t.is_mined == Some(true)) {
+            return Err(rejected(format!("Nonce {} was already used", nonce)));
+        }
+        let replaced = same_nonce.into_iter().find(|t| t.is_pending());
+        if let Some(replaced) = &replaced {
+            if transaction.fee <= replaced.fee {
+                return Err(rejected(format!("Replacing the pending transaction with nonce {} requires a fee higher than {}", nonce, replaced.fee)));
+            }
+        }
+
+        // Step-2 : a replacement takes the place of the replaced transaction, anything else counts towards the cap
+        if replaced.is_none() && sender.pending_count >= self.max_pending_per_sender {
+            return Err(rejected(format!("Sender already has {} pending transactions", self.max_pending_per_sender)));
+        }
+
+        if replaced.is_none() {
+            sender.pending_count += 1;
+        }
+        sender.next_nonce = sender.next_nonce.max(nonce + 1);
+        sender.batch_nonces.insert(nonce);
+        transaction.nonce = Some(nonce);
+        Ok(Admission { transaction, replaced })
+    }
+}
+
+// Highest fee first, the earliest arrival among equal fees, never ahead of a lower nonce of the same sender
+pub fn prioritize(pending: Vec<Transaction>, limit: usize) -> Vec<Transaction> {
+    let mut queues: BTreeMap<Address, Vec<Transaction>> = BTreeMap::new();
+    for transaction in pending {
+        queues.entry(transaction.from_address.clone()).or_default().push(transaction);
+    }
+    let mut queues: Vec<VecDeque<Transaction>> = queues
+        .into_values()
+        .map(|mut queue| {
+            queue.sort_by_key(|t| (t.nonce, t.id));
+            queue.into()
+        })
+        .collect();
+
+    let mut selected = Vec::with_capacity(limit);
+    while selected.len() < limit {
+        let next = queues
+            .iter()
+            .enumerate()
+            .filter_map(|(index, queue)| queue.front().map(|head| (index, head)))
+            .max_by(|(_, a), (_, b)| a.fee.cmp(&b.fee).then(b.id.cmp(&a.id)))
+            .map(|(index, _)| index);
+        match next {
+            Some(index) => selected.extend(queues[index].pop_front()),
+            None => break,
+        }
+    }
+    selected
+}
+
+#[async_trait]
+impl MempoolService for MempoolServiceImpl {
+    #[instrument(name = "mempool_service.submit", skip_all, fields(count = transactions.len(), all_or_nothing))]
+    async fn submit(&self, transactions: Vec<CreateTransaction>, all_or_nothing: bool) -> Result<Vec<Result<Transaction, CommonError>>, CommonError> {
+        self.expire().await?;
+
+        // Two concurrent submissions would otherwise read and take the same next nonce, the unique index of the pending nonces covers the nodes sharing the database
+        let _admission = self.admission_lock.lock().await;
+        let mut senders: HashMap<Address, SenderState> = HashMap::new();
+        let mut admissions = Vec::with_capacity(transactions.len());
+        for transaction in transactions {
+            if !senders.contains_key(&transaction.from_address) {
+                let sender = self.sender_state(&transaction.from_address).await?;
+                senders.insert(transaction.from_address.clone(), sender);
+            }
+            let sender = senders.get_mut(&transaction.from_address).unwrap();
+            admissions.push(self.admit(transaction, sender).await);
+        }
+        if all_or_nothing && admissions.iter().any(Result::is_err) {
+            return Ok(admissions.into_iter().map(|admission| Err(admission.err().unwrap_or_else(batch_rejected))).collect());
+        }
+
+        // Insert the admitted transactions and drop the ones they replace together
+        let admitted: Vec<&Admission> = admissions.iter().filter_map(|admission| admission.as_ref().ok()).collect();
+        let new_transactions: Vec<CreateTransaction> = admitted.iter().map(|admission| admission.transaction.clone()).collect();
+        let replaced: Vec<Transaction> = admitted.iter().filter_map(|admission| admission.replaced.clone()).collect();
+        let replaced_ids: Vec<i32> = replaced.iter().map(|t| t.id).collect();
+        let created_transactions = self.repository.create_many(&new_transactions, &replaced_ids).await.map_err(|e| -> CommonError { e.into() })?;
+
+        for transaction in replaced {
+            self.event_bus.publish(LedgerEvent::TransactionStatus(Transaction {
+                status: TransactionStatus::REPLACED,
+                ..transaction
+            }));
+        }
+        for transaction in &created_transactions {
+            self.event_bus.publish(LedgerEvent::PendingTransaction(transaction.clone()));
+        }
+
+        let mut created_transactions = created_transactions.into_iter();
+        Ok(admissions
+            .into_iter()
+            .map(|admission| admission.map(|_| created_transactions.next().expect("One transaction is created per admission")))
+            .collect())
+    }
+
+    #[instrument(name = "mempool_service.select", skip_all, fields(limit))]
+    async fn select(&self, limit: usize) -> Result<Vec<Transaction>, CommonError> {
+        self.expire().await?;
+        let pending = self.repository.list_pending().await.map_err(|e| -> CommonError { e.into() })?;
+        Ok(prioritize(pending, limit))
+    }
+
+    #[instrument(name = "mempool_service.cancel", skip_all, fields(transaction_hash = %transaction_hash))]
+    async fn cancel(&self, transaction_hash: &str, requester_address: &str) -> Result<Transaction, CommonError> {
+        let transaction = self.repository.get(transaction_hash).await.map_err(|e| -> CommonError { e.into() })?;
+        if transaction.from_address != requester_address {
+            return Err(rejected("Only the sender can cancel a transaction".to_string()));
+        }
+        if !transaction.is_pending() {
+            return Err(rejected(format!("Transaction {} is not pending", transaction_hash)));
+        }
+
+        // Conditional on the transaction still being pending, as the block commit is, so only one of them takes it
+        let cancelled = self
+            .repository
+            .cancel_pending(transaction.id)
+            .await
+            .map_err(|_| rejected(format!("Transaction {} is not pending", transaction_hash)))?;
+        self.event_bus.publish(LedgerEvent::TransactionStatus(cancelled.clone()));
+        Ok(cancelled)
+    }
+
+    #[instrument(name = "mempool_service.expire", skip_all)]
+    async fn expire(&self) -> Result<Vec<Transaction>, CommonError> {
+        let submitted_before = Utc::now().naive_utc() - self.transaction_ttl;
+        let expired = self.repository.expire_pending(submitted_before).await.map_err(|e| -> CommonError { e.into() })?;
+        for transaction in &expired {
+            self.event_bus.publish(LedgerEvent::TransactionStatus(transaction.clone()));
+        }
+        Ok(expired)
+    }
+}
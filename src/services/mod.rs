@@ -1,5 +1,12 @@
 pub mod block;
+pub mod chain;
+pub mod event_bus;
 pub mod fungible_token;
+pub mod health;
+pub mod ledger;
+pub mod ledger_state;
+pub mod mempool;
 pub mod transaction;
 pub mod transaction_helper;
+pub mod vesting;
 pub mod wallet;
@@ -1,89 +1,214 @@
 use actix_web::Result;
 use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use super::transaction_helper::ValidationResult;
+use crate::domain::constants::MAX_TRANSACTION_BATCH_SIZE;
 use crate::domain::error::CommonError;
+use crate::domain::models::address::Address;
 use crate::domain::models::fungible_token::FungibleToken;
-use crate::domain::models::transaction::{CreateTransaction, Transaction, TransactionStatus, UpdateTransaction};
-use crate::domain::models::transaction_type::{BurnFt, InitFt, MintFt, TransactionType, TransferFt};
+use crate::domain::models::ledger::LedgerChanges;
+use crate::domain::models::ledger_event::LedgerEvent;
+use crate::domain::models::transaction::{BalanceDelta, CreateTransaction, Transaction, TransactionReceipt, TransactionSimulation, TransactionStatus, UpdateTransaction};
+use crate::domain::models::transaction_type::{parse_metadata, AdministerFt, BurnFt, ClaimVestedFt, InitFt, MintFt, MultiTransferFt, TransactionType, TransferFt, VestingTransferFt};
+use crate::domain::models::vesting::{CreateVestingSchedule, VestingUnit};
 use crate::domain::models::wallet::Wallet;
 use crate::domain::repositories::repository::ResultPaging;
 use crate::domain::repositories::transaction::{TransactionQueryParams, TransactionRepository};
 use crate::domain::services::fungible_token::FungibleTokenService;
+use crate::domain::services::ledger::LedgerService;
+use crate::domain::services::mempool::MempoolService;
+use crate::domain::services::metrics::MetricsService;
 use crate::domain::services::transaction::TransactionService;
+use crate::domain::services::vesting::VestingService;
 use crate::domain::services::wallet::WalletService;
+use crate::services::fungible_token::{
+    burnt_supply, check_not_frozen, check_not_paused, frozen_wallet, granted_minter, minted_supply, ownership_change, paused_token, renounced_ownership, revoked_minter, symbol_taken,
+};
+use crate::services::ledger_state::{LedgerReader, LedgerState, LedgerStateOverlay, PersistentLedgerState};
+use crate::services::mempool::batch_rejected;
 use crate::services::transaction_helper::validate_transaction_metadata;
-use crate::utils::hex_utils::generate_hex_address;
+use crate::utils::hex_utils::derive_token_address;
+use tracing::instrument;
 
 #[derive(Clone)]
 pub struct TransactionServiceImpl {
     pub repository: Arc<dyn TransactionRepository>,
     pub wallet_service: Arc<dyn WalletService>,
     pub fungible_token_service: Arc<dyn FungibleTokenService>,
+    pub metrics_service: Arc<dyn MetricsService>,
+    pub mempool_service: Arc<dyn MempoolService>,
+    pub vesting_service: Arc<dyn VestingService>,
+    pub ledger_service: Arc<dyn LedgerService>,
+    pub idempotency_key_ttl: Duration,
 }
 
 impl TransactionServiceImpl {
-    pub fn new(repository: Arc<dyn TransactionRepository>, wallet_service: Arc<dyn WalletService>, fungible_token_service: Arc<dyn FungibleTokenService>) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        repository: Arc<dyn TransactionRepository>,
+        wallet_service: Arc<dyn WalletService>,
+        fungible_token_service: Arc<dyn FungibleTokenService>,
+        metrics_service: Arc<dyn MetricsService>,
+        mempool_service: Arc<dyn MempoolService>,
+        vesting_service: Arc<dyn VestingService>,
+        ledger_service: Arc<dyn LedgerService>,
+        idempotency_key_ttl: Duration,
+    ) -> Self {
         TransactionServiceImpl {
             repository,
             wallet_service,
             fungible_token_service,
+            metrics_service,
+            mempool_service,
+            vesting_service,
+            ledger_service,
+            idempotency_key_ttl,
         }
     }
 
-    async fn handle_ft_init(&self, block_number: i32, txn: &Transaction) -> Result<(), CommonError> {
-        let data = txn.data.as_ref().unwrap();
-        let metadata: InitFt = data.clone().into();
+    // Returns the transaction already submitted with the same Idempotency-Key, releasing the key once it expired
+    async fn find_idempotent_submission(&self, transaction: &CreateTransaction) -> Result<Option<Transaction>, CommonError> {
+        let Some(idempotency_key) = &transaction.idempotency_key else {
+            return Ok(None);
+        };
+        let Some(existing) = self
+            .repository
+            .get_by_idempotency_key(&transaction.from_address, idempotency_key)
+            .await
+            .map_err(|e| -> CommonError { e.into() })?
+        else {
+            return Ok(None);
+        };
+
+        let expires_at = existing.timestamp.map(|timestamp| timestamp + self.idempotency_key_ttl);
+        if expires_at.is_some_and(|expires_at| expires_at <= Utc::now().naive_utc()) {
+            self.repository.clear_idempotency_key(existing.id).await.map_err(|e| -> CommonError { e.into() })?;
+            return Ok(None);
+        }
+
+        let same_submission = existing.to_address == transaction.to_address
+            && existing.transaction_type == transaction.transaction_type
+            && existing.value == transaction.value
+            && existing.data == transaction.data
+            && existing.fee == transaction.fee
+            && transaction.nonce.is_none_or(|nonce| nonce == existing.nonce);
+        if !same_submission {
+            return Err(CommonError {
+                message: format!("Idempotency-Key {} was already used for a different transaction", idempotency_key),
+                code: 3,
+            });
+        }
+        Ok(Some(existing))
+    }
 
-        // Step-1 : create the token in the database
+    // A token can only be initialized under a symbol that is neither taken nor reserved for another issuer
+    async fn check_init_ft_symbol(&self, transaction: &CreateTransaction) -> Result<(), CommonError> {
+        if transaction.transaction_type != TransactionType::InitFt {
+            return Ok(());
+        }
+        // Malformed metadata is rejected by the validation
+        let Ok(metadata) = parse_metadata::<InitFt>(transaction.data.as_ref()) else {
+            return Ok(());
+        };
+        self.fungible_token_service.check_symbol_available(&metadata.symbol, &transaction.from_address).await
+    }
+
+    // Runs the handler of the transaction type against the given state, returning the wallets it wrote
+    async fn apply(&self, state: &dyn LedgerState, block_number: i32, txn: &Transaction) -> Result<Vec<Wallet>, CommonError> {
+        match txn.transaction_type {
+            TransactionType::InitFt => self.handle_ft_init(state, block_number, txn).await,
+            TransactionType::MintFt => self.handle_ft_mint(state, block_number, txn).await,
+            TransactionType::BurnFt => self.handle_ft_burn(state, block_number, txn).await,
+            TransactionType::TransferFt => self.handle_ft_transfer(state, block_number, txn).await,
+            TransactionType::MultiTransferFt => self.handle_ft_multi_transfer(state, block_number, txn).await,
+            TransactionType::TransferOwnershipFt | TransactionType::RenounceOwnershipFt | TransactionType::GrantMinterFt | TransactionType::RevokeMinterFt => {
+                self.handle_ft_administration(state, block_number, txn).await
+            }
+            TransactionType::PauseFt | TransactionType::UnpauseFt | TransactionType::FreezeAccount | TransactionType::UnfreezeAccount => {
+                self.handle_ft_emergency_control(state, block_number, txn).await
+            }
+            TransactionType::VestingTransferFt => self.handle_ft_vesting_transfer(state, block_number, txn).await,
+            TransactionType::ClaimVestedFt => self.handle_ft_vesting_claim(state, block_number, txn).await,
+            // NFT transactions are not supported yet
+            TransactionType::InitNft | TransactionType::MintNft | TransactionType::BurnNft | TransactionType::TransferNft => Err(CommonError {
+                message: format!("Unsupported transaction type {}", txn.transaction_type.as_str()),
+                code: 3,
+            }),
+        }
+    }
+
+    async fn handle_ft_init(&self, state: &dyn LedgerState, block_number: i32, txn: &Transaction) -> Result<Vec<Wallet>, CommonError> {
+        let metadata: InitFt = parse_metadata(txn.data.as_ref())?;
+
+        // Step-1 : create the token, unless a token created since the submission took its symbol
+        if state.find_token_by_symbol(&metadata.symbol).await?.is_some() {
+            return Err(symbol_taken(&metadata.symbol));
+        }
+        let token_address = derive_token_address(&txn.from_address, txn.nonce);
+        if state.get_token(&token_address).await.is_ok() {
+            return Err(CommonError {
+                message: format!("Token address {} is already taken", token_address),
+                code: 2,
+            });
+        }
         let fungible_token = FungibleToken {
-            address: generate_hex_address(),
+            address: token_address,
             symbol: metadata.symbol.clone(),
             name: metadata.name.clone(),
             owner_address: txn.from_address.clone(),
             decimals: metadata.decimals,
-            total_supply: txn.value.into(),
-            block_number: block_number,
+            total_supply: txn.value,
+            block_number,
             transaction_hash: txn.transaction_hash.clone(),
+            minter_addresses: vec![],
+            max_supply: metadata.max_supply,
+            mintable: metadata.mintable,
+            burnable: metadata.burnable,
+            paused: false,
         };
-        let new_token = self.fungible_token_service.create(fungible_token).await?;
+        let new_token = state.create_token(fungible_token).await?;
 
         // Step-2 : create the wallet for the owner and credit the initial supply
         let new_wallet = Wallet {
             address: txn.from_address.clone(),
             token_address: new_token.address.clone(),
             balance: txn.value,
-            block_number: block_number,
+            block_number,
             transaction_hash: txn.transaction_hash.clone(),
+            frozen: false,
         };
-        self.wallet_service.create_or_update(new_wallet).await?;
-        Ok(())
+        state.put_wallets(vec![new_wallet]).await
     }
 
-    async fn handle_ft_transfer(&self, block_number: i32, txn: &Transaction) -> Result<(), CommonError> {
-        let data = txn.data.as_ref().unwrap();
-        let metadata: TransferFt = data.clone().into();
+    async fn handle_ft_transfer(&self, state: &dyn LedgerState, block_number: i32, txn: &Transaction) -> Result<Vec<Wallet>, CommonError> {
+        let metadata: TransferFt = parse_metadata(txn.data.as_ref())?;
+        check_not_paused(&state.get_token(&metadata.token_address).await?)?;
 
         // Step-1 : update the balance of the from_wallet
-        let mut from_wallet = self.wallet_service.get(&txn.from_address, &metadata.token_address).await?;
+        let mut from_wallet = state.get_wallet(&txn.from_address, &metadata.token_address).await?;
+        check_not_frozen(&from_wallet)?;
         if from_wallet.balance >= txn.value {
             from_wallet.balance -= txn.value;
             from_wallet.block_number = block_number;
             from_wallet.transaction_hash = txn.transaction_hash.clone();
 
             // Step-2 : update the balance of the to_wallet
-            let to_wallet_result = self.wallet_service.get(&txn.to_address, &metadata.token_address).await;
+            let to_wallet_result = state.get_wallet(&txn.to_address, &metadata.token_address).await;
             // Get or create from_wallet
             let to_wallet = match to_wallet_result {
                 Ok(existing_wallet) => {
+                    check_not_frozen(&existing_wallet)?;
                     // Update the balance
                     Wallet {
                         address: txn.to_address.clone(),
                         token_address: metadata.token_address.clone(),
                         balance: existing_wallet.balance + txn.value,
-                        block_number: block_number,
+                        block_number,
                         transaction_hash: txn.transaction_hash.clone(),
+                        frozen: false,
                     }
                 }
                 Err(_) => {
@@ -92,16 +217,15 @@ impl TransactionServiceImpl {
                         address: txn.to_address.clone(),
                         token_address: metadata.token_address.clone(),
                         balance: txn.value,
-                        block_number: block_number,
+                        block_number,
                         transaction_hash: txn.transaction_hash.clone(),
+                        frozen: false,
                     }
                 }
             };
 
             // Step-3 : persist the updated wallets
-            self.wallet_service.create_or_update(from_wallet).await?;
-            self.wallet_service.create_or_update(to_wallet).await?;
-            Ok(())
+            state.put_wallets(vec![from_wallet, to_wallet]).await
         } else {
             Err(CommonError {
                 message: "Insufficient balance in sender's wallet".to_string(),
@@ -110,24 +234,72 @@ impl TransactionServiceImpl {
         }
     }
 
-    async fn handle_ft_mint(&self, block_number: i32, txn: &Transaction) -> Result<(), CommonError> {
-        let data = txn.data.as_ref().unwrap();
-        let metadata: MintFt = data.clone().into();
+    async fn handle_ft_multi_transfer(&self, state: &dyn LedgerState, block_number: i32, txn: &Transaction) -> Result<Vec<Wallet>, CommonError> {
+        let metadata: MultiTransferFt = parse_metadata(txn.data.as_ref())?;
+        check_not_paused(&state.get_token(&metadata.token_address).await?)?;
+
+        // Step-1 : check the sender covers the sum of the transfers
+        let from_wallet = state.get_wallet(&txn.from_address, &metadata.token_address).await?;
+        check_not_frozen(&from_wallet)?;
+        let total_amount = metadata.total_amount().ok_or_else(|| CommonError {
+            message: "Transfer amounts overflow".to_string(),
+            code: 3,
+        })?;
+        if from_wallet.balance < total_amount {
+            return Err(CommonError {
+                message: "Insufficient balance in sender's wallet".to_string(),
+                code: 1,
+            });
+        }
+
+        // Step-2 : compute every new balance before persisting any, a recipient may appear several times or be the sender
+        let mut balances: BTreeMap<Address, i64> = BTreeMap::new();
+        balances.insert(txn.from_address.clone(), from_wallet.balance - total_amount);
+        for transfer in &metadata.transfers {
+            if !balances.contains_key(&transfer.to_address) {
+                let balance = match state.get_wallet(&transfer.to_address, &metadata.token_address).await {
+                    Ok(existing_wallet) => {
+                        check_not_frozen(&existing_wallet)?;
+                        existing_wallet.balance
+                    }
+                    Err(_) => 0,
+                };
+                balances.insert(transfer.to_address.clone(), balance);
+            }
+            *balances.get_mut(&transfer.to_address).unwrap() += transfer.amount;
+        }
+
+        // Step-3 : persist the updated wallets together, so either every recipient is credited or none
+        let updated_wallets = balances
+            .into_iter()
+            .map(|(address, balance)| Wallet {
+                address,
+                token_address: metadata.token_address.clone(),
+                balance,
+                block_number,
+                transaction_hash: txn.transaction_hash.clone(),
+                frozen: false,
+            })
+            .collect();
+        state.put_wallets(updated_wallets).await
+    }
 
-        // Step-1 : mint the new token
-        self.fungible_token_service.mint(&metadata.token_address, &txn.from_address, txn.value).await?;
+    async fn handle_ft_mint(&self, state: &dyn LedgerState, block_number: i32, txn: &Transaction) -> Result<Vec<Wallet>, CommonError> {
+        let metadata: MintFt = parse_metadata(txn.data.as_ref())?;
 
-        // Step-2 : if mint was successful, update the balance of the to_wallet
-        let to_wallet_result = self.wallet_service.get(&txn.to_address, &metadata.token_address).await;
+        // Step-1 : prepare the balance of the to_wallet, refusing a frozen one before anything is minted
+        let to_wallet_result = state.get_wallet(&txn.to_address, &metadata.token_address).await;
         let mint_to_wallet = match to_wallet_result {
             Ok(existing_wallet) => {
+                check_not_frozen(&existing_wallet)?;
                 // Update the balance
                 Wallet {
                     address: txn.to_address.clone(),
                     token_address: metadata.token_address.clone(),
                     balance: existing_wallet.balance + txn.value,
-                    block_number: block_number,
+                    block_number,
                     transaction_hash: txn.transaction_hash.clone(),
+                    frozen: false,
                 }
             }
             Err(_) => {
@@ -136,120 +308,410 @@ impl TransactionServiceImpl {
                     address: txn.to_address.clone(),
                     token_address: metadata.token_address.clone(),
                     balance: txn.value,
-                    block_number: block_number,
+                    block_number,
                     transaction_hash: txn.transaction_hash.clone(),
+                    frozen: false,
                 }
             }
         };
 
-        // Step-3 : persist the updated wallet
-        self.wallet_service.create_or_update(mint_to_wallet).await?;
-        Ok(())
+        // Step-2 : mint the new token
+        let token = state.get_token(&metadata.token_address).await?;
+        state.update_token(&metadata.token_address, minted_supply(&token, &txn.from_address, txn.value)?).await?;
+
+        // Step-3 : if mint was successful, persist the updated wallet
+        state.put_wallets(vec![mint_to_wallet]).await
     }
 
-    async fn handle_ft_burn(&self, block_number: i32, txn: &Transaction) -> Result<(), CommonError> {
-        let data = txn.data.as_ref().unwrap();
-        let metadata: BurnFt = data.clone().into();
+    // Ownership and minter role changes touch no wallet
+    async fn handle_ft_administration(&self, state: &dyn LedgerState, block_number: i32, txn: &Transaction) -> Result<Vec<Wallet>, CommonError> {
+        let metadata: AdministerFt = parse_metadata(txn.data.as_ref())?;
+
+        let token = state.get_token(&metadata.token_address).await?;
+        match txn.transaction_type {
+            TransactionType::TransferOwnershipFt => {
+                let change = ownership_change(&token, &txn.from_address, &txn.to_address, block_number, &txn.transaction_hash)?;
+                state.change_token_owner(change).await?;
+            }
+            TransactionType::RenounceOwnershipFt => {
+                let change = renounced_ownership(&token, &txn.from_address, block_number, &txn.transaction_hash)?;
+                state.change_token_owner(change).await?;
+            }
+            TransactionType::GrantMinterFt => {
+                state.update_token(&metadata.token_address, granted_minter(&token, &txn.from_address, &txn.to_address)?).await?;
+            }
+            TransactionType::RevokeMinterFt => {
+                state.update_token(&metadata.token_address, revoked_minter(&token, &txn.from_address, &txn.to_address)?).await?;
+            }
+            _ => {
+                return Err(CommonError {
+                    message: format!("Unsupported transaction type {}", txn.transaction_type.as_str()),
+                    code: 3,
+                })
+            }
+        }
+        Ok(vec![])
+    }
+
+    // Pausing halts the whole token, freezing a single wallet of it, the wallet is created when the account holds none yet
+    async fn handle_ft_emergency_control(&self, state: &dyn LedgerState, block_number: i32, txn: &Transaction) -> Result<Vec<Wallet>, CommonError> {
+        let metadata: AdministerFt = parse_metadata(txn.data.as_ref())?;
+
+        let token = state.get_token(&metadata.token_address).await?;
+        match txn.transaction_type {
+            TransactionType::PauseFt | TransactionType::UnpauseFt => {
+                let paused = txn.transaction_type == TransactionType::PauseFt;
+                state.update_token(&metadata.token_address, paused_token(&token, &txn.from_address, paused)?).await?;
+                Ok(vec![])
+            }
+            TransactionType::FreezeAccount | TransactionType::UnfreezeAccount => {
+                let frozen = txn.transaction_type == TransactionType::FreezeAccount;
+                let wallet = state.get_wallet(&txn.to_address, &metadata.token_address).await.unwrap_or_else(|_| Wallet {
+                    address: txn.to_address.clone(),
+                    token_address: metadata.token_address.clone(),
+                    balance: 0,
+                    block_number,
+                    transaction_hash: txn.transaction_hash.clone(),
+                    frozen: false,
+                });
+                let updated_wallet = frozen_wallet(&token, &txn.from_address, wallet, frozen, block_number, &txn.transaction_hash)?;
+                state.put_wallets(vec![updated_wallet]).await
+            }
+            _ => Err(CommonError {
+                message: format!("Unsupported transaction type {}", txn.transaction_type.as_str()),
+                code: 3,
+            }),
+        }
+    }
+
+    async fn handle_ft_burn(&self, state: &dyn LedgerState, block_number: i32, txn: &Transaction) -> Result<Vec<Wallet>, CommonError> {
+        let metadata: BurnFt = parse_metadata(txn.data.as_ref())?;
 
         // Step-1 : update the balance of the from_wallet
-        let from_wallet = self.wallet_service.get(&txn.from_address, &metadata.token_address).await?;
-        self.fungible_token_service.burn(&metadata.token_address, &from_wallet, txn.value).await?;
+        let from_wallet = state.get_wallet(&txn.from_address, &metadata.token_address).await?;
+        let token = state.get_token(&metadata.token_address).await?;
+        state.update_token(&metadata.token_address, burnt_supply(&token, &from_wallet, txn.value)?).await?;
         let updated_wallet = Wallet {
             address: txn.from_address.clone(),
             token_address: metadata.token_address.clone(),
             balance: from_wallet.balance - txn.value,
-            block_number: block_number,
+            block_number,
             transaction_hash: txn.transaction_hash.clone(),
+            frozen: false,
         };
 
         // Step-2 : persist the updated wallet
-        self.wallet_service.create_or_update(updated_wallet).await?;
-        Ok(())
+        state.put_wallets(vec![updated_wallet]).await
+    }
+
+    // The value leaves the grantor now and is held by the schedule until the beneficiary claims it
+    async fn handle_ft_vesting_transfer(&self, state: &dyn LedgerState, block_number: i32, txn: &Transaction) -> Result<Vec<Wallet>, CommonError> {
+        let metadata: VestingTransferFt = parse_metadata(txn.data.as_ref())?;
+        check_not_paused(&state.get_token(&metadata.token_address).await?)?;
+
+        // Step-1 : update the balance of the from_wallet
+        let mut from_wallet = state.get_wallet(&txn.from_address, &metadata.token_address).await?;
+        check_not_frozen(&from_wallet)?;
+        if from_wallet.balance < txn.value {
+            return Err(CommonError {
+                message: "Insufficient balance in sender's wallet".to_string(),
+                code: 1,
+            });
+        }
+        from_wallet.balance -= txn.value;
+        from_wallet.block_number = block_number;
+        from_wallet.transaction_hash = txn.transaction_hash.clone();
+
+        // Step-2 : lock the value in a schedule starting now unless told otherwise
+        let start = metadata.start.unwrap_or_else(|| match metadata.unit {
+            VestingUnit::BLOCK => block_number as i64,
+            VestingUnit::TIMESTAMP => Utc::now().timestamp(),
+        });
+        state
+            .create_vesting(CreateVestingSchedule {
+                token_address: metadata.token_address,
+                grantor_address: txn.from_address.clone(),
+                beneficiary_address: txn.to_address.clone(),
+                unit: metadata.unit,
+                start,
+                cliff: metadata.cliff,
+                duration: metadata.duration,
+                total_amount: txn.value,
+                block_number,
+                transaction_hash: txn.transaction_hash.clone(),
+            })
+            .await?;
+
+        // Step-3 : persist the updated wallet
+        state.put_wallets(vec![from_wallet]).await
+    }
+
+    // Releases to the sender whatever vested so far across its schedules of the token
+    async fn handle_ft_vesting_claim(&self, state: &dyn LedgerState, block_number: i32, txn: &Transaction) -> Result<Vec<Wallet>, CommonError> {
+        let metadata: ClaimVestedFt = parse_metadata(txn.data.as_ref())?;
+        check_not_paused(&state.get_token(&metadata.token_address).await?)?;
+
+        // Step-1 : sum what vested and was not claimed yet
+        let timestamp = Utc::now().timestamp();
+        let schedules = state.list_vestings(&txn.from_address, &metadata.token_address).await?;
+        let claimable_amount: i64 = schedules.iter().map(|schedule| schedule.claimable_amount(block_number, timestamp)).sum();
+        if claimable_amount <= 0 {
+            return Err(CommonError {
+                message: "Nothing vested to claim".to_string(),
+                code: 2,
+            });
+        }
+
+        // Step-2 : prepare the balance of the beneficiary, refusing a frozen one before anything is released
+        let balance = match state.get_wallet(&txn.from_address, &metadata.token_address).await {
+            Ok(existing_wallet) => {
+                check_not_frozen(&existing_wallet)?;
+                existing_wallet.balance
+            }
+            Err(_) => 0,
+        };
+        let updated_wallet = Wallet {
+            address: txn.from_address.clone(),
+            token_address: metadata.token_address.clone(),
+            balance: balance + claimable_amount,
+            block_number,
+            transaction_hash: txn.transaction_hash.clone(),
+            frozen: false,
+        };
+
+        // Step-3 : mark the vested amounts claimed, then persist the updated wallet
+        for schedule in schedules.iter().filter(|schedule| schedule.claimable_amount(block_number, timestamp) > 0) {
+            state.update_vesting_claimed(schedule, schedule.vested_amount(block_number, timestamp)).await?;
+        }
+        state.put_wallets(vec![updated_wallet]).await
+    }
+}
+
+// Address of the token an INIT_FT created, kept on the mined transaction
+fn token_created_by(changes: &LedgerChanges) -> Option<Address> {
+    changes.created_tokens.first().map(|token| token.address.clone())
+}
+
+fn validate_create_transaction(transaction: &CreateTransaction) -> Result<(), CommonError> {
+    match validate_transaction_metadata(transaction) {
+        ValidationResult::Valid => Ok(()),
+        ValidationResult::Invalid(error_messages) => Err(CommonError {
+            message: format!("Invalid transaction metadata: {}", error_messages),
+            code: 3,
+        }),
     }
 }
 
 #[async_trait]
 impl TransactionService for TransactionServiceImpl {
+    #[instrument(name = "transaction_service.create", skip_all, fields(transaction_type = %transaction.transaction_type.as_str()))]
     async fn create(&self, transaction: CreateTransaction) -> Result<Transaction, CommonError> {
-        let validation_result = validate_transaction_metadata(&transaction);
-        if let ValidationResult::Invalid(error_messages) = validation_result {
+        validate_create_transaction(&transaction)?;
+
+        if let Some(existing) = self.find_idempotent_submission(&transaction).await? {
+            return Ok(existing);
+        }
+        self.check_init_ft_symbol(&transaction).await?;
+
+        let submission = match self.mempool_service.submit(vec![transaction.clone()], true).await {
+            Ok(mut results) => results.remove(0),
+            Err(e) => Err(e),
+        };
+        match submission {
+            Ok(created) => Ok(created),
+            // A concurrent retry may have inserted the same submission in between
+            Err(e) => match self.find_idempotent_submission(&transaction).await? {
+                Some(existing) => Ok(existing),
+                None => Err(e),
+            },
+        }
+    }
+
+    #[instrument(name = "transaction_service.create_batch", skip_all, fields(count = transactions.len(), all_or_nothing))]
+    async fn create_batch(&self, transactions: Vec<Result<CreateTransaction, CommonError>>, all_or_nothing: bool) -> Result<Vec<Result<Transaction, CommonError>>, CommonError> {
+        if transactions.len() > MAX_TRANSACTION_BATCH_SIZE {
             return Err(CommonError {
-                message: format!("Invalid transaction metadata: {}", error_messages),
+                message: format!("A batch holds at most {} transactions", MAX_TRANSACTION_BATCH_SIZE),
                 code: 3,
             });
         }
 
-        let mut cloned = transaction.clone();
-        self.repository.create(&mut cloned).await.map_err(|e| -> CommonError { e.into() })
+        let mut validations: Vec<Result<(), CommonError>> = Vec::with_capacity(transactions.len());
+        let mut valid_transactions: Vec<CreateTransaction> = Vec::with_capacity(transactions.len());
+        for transaction in transactions {
+            let validation = match transaction {
+                Ok(transaction) => {
+                    let validation = match validate_create_transaction(&transaction) {
+                        Ok(()) => self.check_init_ft_symbol(&transaction).await,
+                        Err(e) => Err(e),
+                    };
+                    if validation.is_ok() {
+                        valid_transactions.push(transaction);
+                    }
+                    validation
+                }
+                Err(e) => Err(e),
+            };
+            validations.push(validation);
+        }
+        if all_or_nothing && validations.iter().any(Result::is_err) {
+            return Ok(validations.into_iter().map(|validation| Err(validation.err().unwrap_or_else(batch_rejected))).collect());
+        }
+
+        let mut submissions = self.mempool_service.submit(valid_transactions, all_or_nothing).await?.into_iter();
+        Ok(validations
+            .into_iter()
+            .map(|validation| validation.and_then(|_| submissions.next().expect("One submission result per valid transaction")))
+            .collect())
     }
 
+    #[instrument(name = "transaction_service.list", skip_all, fields(params = ?params))]
     async fn list(&self, params: TransactionQueryParams) -> Result<ResultPaging<Transaction>, CommonError> {
         self.repository.list(params).await.map_err(|e| -> CommonError { e.into() })
     }
 
+    #[instrument(name = "transaction_service.pending_count", skip_all)]
+    async fn pending_count(&self) -> Result<i64, CommonError> {
+        self.repository.count_pending(None).await.map_err(|e| -> CommonError { e.into() })
+    }
+
+    #[instrument(name = "transaction_service.list_by_block", skip_all, fields(block_number))]
+    async fn list_by_block(&self, block_number: i32) -> Result<Vec<Transaction>, CommonError> {
+        self.repository.list_by_block(block_number).await.map_err(|e| -> CommonError { e.into() })
+    }
+
+    #[instrument(name = "transaction_service.get", skip_all, fields(transaction_hash = %transaction_hash))]
     async fn get(&self, transaction_hash: &str) -> Result<Transaction, CommonError> {
         self.repository.get(transaction_hash).await.map_err(|e| -> CommonError { e.into() })
     }
 
-    async fn delete(&self, transaction_id: i32) -> Result<(), CommonError> {
-        self.repository.delete(transaction_id).await.map_err(|e| -> CommonError { e.into() })
+    #[instrument(name = "transaction_service.cancel", skip_all, fields(transaction_hash = %transaction_hash))]
+    async fn cancel(&self, transaction_hash: &str, requester_address: &str) -> Result<Transaction, CommonError> {
+        self.mempool_service.cancel(transaction_hash, requester_address).await
     }
 
-    async fn update(&self, transaction_id: i32, update_data: UpdateTransaction) -> Result<Transaction, CommonError> {
-        self.repository.update(transaction_id, update_data).await.map_err(|e| -> CommonError { e.into() })
+    #[instrument(name = "transaction_service.mine_pending", skip_all, fields(transaction_id))]
+    async fn mine_pending(&self, transaction_id: i32, update_data: UpdateTransaction) -> Result<Option<Transaction>, CommonError> {
+        self.repository.mine_pending(transaction_id, update_data).await.map_err(|e| -> CommonError { e.into() })
     }
 
-    async fn execute(&self, block_number: i32, txn: &Transaction) -> Result<(), CommonError> {
-        let mut txn_status: TransactionStatus = TransactionStatus::SUCCESS;
-        match txn.transaction_type {
-            TransactionType::InitFt => {
-                if let Err(_) = self.handle_ft_init(block_number, txn).await {
-                    txn_status = TransactionStatus::FAIL;
-                }
-            }
-            TransactionType::MintFt => {
-                if let Err(_) = self.handle_ft_mint(block_number, txn).await {
-                    txn_status = TransactionStatus::FAIL;
-                }
-            }
-            TransactionType::BurnFt => {
-                if let Err(_) = self.handle_ft_burn(block_number, txn).await {
-                    txn_status = TransactionStatus::FAIL;
-                }
-            }
-            TransactionType::TransferFt => {
-                if let Err(_) = self.handle_ft_transfer(block_number, txn).await {
-                    txn_status = TransactionStatus::FAIL;
-                }
-            }
-            TransactionType::InitNft => {
-                // Handle InitFt case
-                txn_status = TransactionStatus::FAIL;
-            }
-            TransactionType::MintNft => {
-                // Handle MintFt case
-                txn_status = TransactionStatus::FAIL;
-            }
-            TransactionType::BurnNft => {
-                // Handle BurnFt case
-                txn_status = TransactionStatus::FAIL;
-            }
-            TransactionType::TransferNft => {
-                // Handle TransferFt case
-                txn_status = TransactionStatus::FAIL;
-            }
-            TransactionType::None => {
-                txn_status = TransactionStatus::FAIL;
-            }
+    #[instrument(name = "transaction_service.simulate", skip_all, fields(block_number, transaction_type = %transaction.transaction_type.as_str()))]
+    async fn simulate(&self, block_number: i32, transaction: CreateTransaction) -> Result<TransactionSimulation, CommonError> {
+        validate_create_transaction(&transaction)?;
+        self.check_init_ft_symbol(&transaction).await?;
+        let txn = Transaction {
+            id: 0,
+            block_number: Some(block_number),
+            transaction_hash: transaction.transaction_hash,
+            from_address: transaction.from_address,
+            to_address: transaction.to_address,
+            transaction_type: transaction.transaction_type,
+            value: transaction.value,
+            timestamp: transaction.timestamp,
+            data: transaction.data,
+            is_mined: Some(false),
+            status: TransactionStatus::RAW,
+            idempotency_key: None,
+            fee: transaction.fee,
+            nonce: transaction.nonce.unwrap_or_default(),
+            created_token_address: None,
+        };
+
+        // The handlers write to an overlay of the current state, dropped once the outcome is known
+        let state: Arc<dyn LedgerReader> = Arc::new(PersistentLedgerState::new(
+            self.wallet_service.clone(),
+            self.fungible_token_service.clone(),
+            self.vesting_service.clone(),
+        ));
+        let overlay = LedgerStateOverlay::new(state.clone());
+        let (status, error, updated_wallets, created_token_address) = match self.apply(&overlay, block_number, &txn).await {
+            Ok(_) => (TransactionStatus::SUCCESS, None, overlay.wallets(), token_created_by(&overlay.changes())),
+            // A failed transaction changes no wallet, whatever its handler wrote before failing
+            Err(e) => (TransactionStatus::FAIL, Some(e.message), vec![], None),
+        };
+
+        let mut balance_deltas = Vec::with_capacity(updated_wallets.len());
+        for wallet in &updated_wallets {
+            let balance_before = match state.get_wallet(&wallet.address, &wallet.token_address).await {
+                Ok(existing_wallet) => existing_wallet.balance,
+                Err(_) => 0,
+            };
+            balance_deltas.push(BalanceDelta {
+                address: wallet.address.clone(),
+                token_address: wallet.token_address.clone(),
+                balance_before,
+                balance_after: wallet.balance,
+            });
         }
 
-        // Update the transaction is_mined and block_number
-        let updated_txn = UpdateTransaction {
+        let transaction = Transaction {
+            is_mined: Some(true),
+            status: status.clone(),
+            created_token_address,
+            ..txn
+        };
+        let mut events = vec![LedgerEvent::TransactionStatus(transaction)];
+        events.extend(updated_wallets.into_iter().map(LedgerEvent::WalletChanged));
+        Ok(TransactionSimulation {
+            status,
+            error,
+            balance_deltas,
+            events,
+        })
+    }
+
+    #[instrument(name = "transaction_service.execute", skip_all, fields(block_number, transaction_hash = %txn.transaction_hash, transaction_type = %txn.transaction_type.as_str()))]
+    async fn execute(&self, block_number: i32, txn: &Transaction) -> Result<Option<TransactionReceipt>, CommonError> {
+        // The handlers write to an overlay of the current state, committed together with the transaction status
+        let overlay = LedgerStateOverlay::new(Arc::new(PersistentLedgerState::new(
+            self.wallet_service.clone(),
+            self.fungible_token_service.clone(),
+            self.vesting_service.clone(),
+        )));
+        let mined_txn = |status: TransactionStatus, created_token_address: Option<Address>| UpdateTransaction {
             is_mined: Some(true),
             block_number: Some(block_number),
-            status: Some(txn_status),
+            status: Some(status),
+            created_token_address,
         };
-        self.update(txn.id, updated_txn).await?;
-        Ok(())
+        let execution_result = match self.apply(&overlay, block_number, txn).await {
+            Ok(updated_wallets) => {
+                let changes = overlay.changes();
+                let created_token_address = token_created_by(&changes);
+                let changes = LedgerChanges {
+                    transaction: Some((txn.id, mined_txn(TransactionStatus::SUCCESS, created_token_address.clone()))),
+                    ..changes
+                };
+                self.ledger_service.commit(changes).await.map(|_| (updated_wallets, created_token_address))
+            }
+            Err(e) => Err(e),
+        };
+        let (txn_status, updated_wallets, created_token_address) = match execution_result {
+            Ok((updated_wallets, created_token_address)) => (TransactionStatus::SUCCESS, updated_wallets, created_token_address),
+            Err(_) => (TransactionStatus::FAIL, vec![], None),
+        };
+
+        // A failed transaction changes nothing but its own status, unless it was cancelled or expired in the meantime
+        let transaction = if txn_status == TransactionStatus::SUCCESS {
+            Transaction {
+                is_mined: Some(true),
+                block_number: Some(block_number),
+                status: txn_status.clone(),
+                created_token_address,
+                ..txn.clone()
+            }
+        } else {
+            match self.mine_pending(txn.id, mined_txn(txn_status.clone(), None)).await? {
+                Some(transaction) => transaction,
+                None => return Ok(None),
+            }
+        };
+
+        self.metrics_service.record_transaction_executed(txn.transaction_type.as_str(), &txn_status.to_string());
+        Ok(Some(TransactionReceipt {
+            token_address: transaction.token_address(),
+            transaction,
+            updated_wallets,
+        }))
     }
 }
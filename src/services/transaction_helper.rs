@@ -1,18 +1,16 @@
-use serde_json::Value;
-
 pub enum ValidationResult {
     Valid,
     Invalid(String), // String contains error message when validation fails
 }
 
+use crate::domain::constants::{MAX_MULTI_TRANSFER_RECIPIENTS, ZERO_ADDRESS};
 use crate::domain::models::{
     transaction::CreateTransaction,
-    transaction_type::{BurnFt, InitFt, MintFt, TransactionType, TransferFt},
+    transaction_type::{parse_metadata, AdministerFt, BurnFt, ClaimVestedFt, InitFt, MintFt, MultiTransferFt, TransactionType, TransferFt, VestingTransferFt},
 };
 
-// Should check symbol and name are specified
-fn validate_init_ft(data: &Value) -> ValidationResult {
-    let metadata: InitFt = data.clone().into();
+// Should check symbol and name are specified, and that the optional max supply and mint policies are well formed
+fn validate_init_ft(metadata: InitFt, txn: &CreateTransaction) -> ValidationResult {
     let mut error_messages = String::new();
 
     if !is_non_empty_string(&metadata.symbol) {
@@ -23,6 +21,14 @@ fn validate_init_ft(data: &Value) -> ValidationResult {
         error_messages += "Name is missing or empty. ";
     }
 
+    if metadata.max_supply.is_some_and(|max_supply| max_supply <= 0) {
+        error_messages += "Max supply must be greater than zero. ";
+    }
+
+    if metadata.max_supply.is_some_and(|max_supply| txn.value > max_supply) {
+        error_messages += "Value must not exceed the max supply. ";
+    }
+
     if error_messages.is_empty() {
         ValidationResult::Valid
     } else {
@@ -31,21 +37,38 @@ fn validate_init_ft(data: &Value) -> ValidationResult {
 }
 
 // Should check who is minting, to whom is token being minted, the quantity and which token
-fn validate_mint_ft(data: &Value, txn: &CreateTransaction) -> ValidationResult {
-    let metadata: MintFt = data.clone().into();
+fn validate_mint_ft(txn: &CreateTransaction) -> ValidationResult {
     let mut error_messages = String::new();
 
-    if !is_non_empty_string(&metadata.token_address) {
-        error_messages += "Token address is missing or empty. ";
+    if txn.value <= 0 {
+        error_messages += "Value must be greater than zero. ";
     }
 
-    if !is_non_empty_string(&txn.from_address) {
-        error_messages += "From address is missing or empty. ";
+    if error_messages.is_empty() {
+        ValidationResult::Valid
+    } else {
+        ValidationResult::Invalid(error_messages)
+    }
+}
+
+// Should check who is burning, which token is being burnt, the quantity and which token
+fn validate_burn_ft(txn: &CreateTransaction) -> ValidationResult {
+    let mut error_messages = String::new();
+
+    if txn.value <= 0 {
+        error_messages += "Value must be greater than zero. ";
     }
 
-    if !is_non_empty_string(&txn.to_address) {
-        error_messages += "To address is missing or empty. ";
+    if error_messages.is_empty() {
+        ValidationResult::Valid
+    } else {
+        ValidationResult::Invalid(error_messages)
     }
+}
+
+// Should check who is transferring, which token is being transferred, the quantity and to whom is token being transferred
+fn validate_transfer_ft(txn: &CreateTransaction) -> ValidationResult {
+    let mut error_messages = String::new();
 
     if txn.value <= 0 {
         error_messages += "Value must be greater than zero. ";
@@ -58,21 +81,24 @@ fn validate_mint_ft(data: &Value, txn: &CreateTransaction) -> ValidationResult {
     }
 }
 
-// Should check who is burning, which token is being burnt, the quantity and which token
-fn validate_burn_ft(data: &Value, txn: &CreateTransaction) -> ValidationResult {
-    let metadata: BurnFt = data.clone().into();
+// Should check who is transferring, which token, every recipient with its amount, and that the value is the sum of the amounts
+fn validate_multi_transfer_ft(metadata: MultiTransferFt, txn: &CreateTransaction) -> ValidationResult {
     let mut error_messages = String::new();
 
-    if !is_non_empty_string(&metadata.token_address) {
-        error_messages += "Token address is missing or empty. ";
+    if metadata.transfers.is_empty() {
+        error_messages += "Transfers are missing or empty. ";
     }
 
-    if !is_non_empty_string(&txn.from_address) {
-        error_messages += "From address is missing or empty. ";
+    if metadata.transfers.len() > MAX_MULTI_TRANSFER_RECIPIENTS {
+        error_messages += &format!("At most {} transfers are allowed. ", MAX_MULTI_TRANSFER_RECIPIENTS);
     }
 
-    if txn.value <= 0 {
-        error_messages += "Value must be greater than zero. ";
+    if metadata.transfers.iter().any(|transfer| transfer.amount <= 0) {
+        error_messages += "Transfer amount must be greater than zero. ";
+    }
+
+    if metadata.total_amount() != Some(txn.value) {
+        error_messages += "Value must be the sum of the transfer amounts. ";
     }
 
     if error_messages.is_empty() {
@@ -82,27 +108,64 @@ fn validate_burn_ft(data: &Value, txn: &CreateTransaction) -> ValidationResult {
     }
 }
 
-// Should check who is transferring, which token is being transferred, the quantity and to whom is token being transferred
-fn validate_transfer_ft(data: &Value, txn: &CreateTransaction) -> ValidationResult {
-    let metadata: TransferFt = data.clone().into();
+// Should check who administers which token, the new owner, the minter or the account unless renouncing or pausing, and that no value is moved
+fn validate_administer_ft(txn: &CreateTransaction) -> ValidationResult {
     let mut error_messages = String::new();
 
-    if !is_non_empty_string(&metadata.token_address) {
-        error_messages += "Token address is missing or empty. ";
+    if txn.transaction_type == TransactionType::TransferOwnershipFt && txn.to_address == ZERO_ADDRESS {
+        error_messages += "To address must not be the zero address, use RENOUNCE_OWNERSHIP_FT. ";
     }
 
-    if !is_non_empty_string(&txn.from_address) {
-        error_messages += "From address is missing or empty. ";
+    if txn.value != 0 {
+        error_messages += "Value must be zero. ";
     }
 
-    if !is_non_empty_string(&txn.to_address) {
-        error_messages += "To address is missing or empty. ";
+    if error_messages.is_empty() {
+        ValidationResult::Valid
+    } else {
+        ValidationResult::Invalid(error_messages)
     }
+}
+
+// Should check the locked quantity, the beneficiary, and that the cliff falls within the duration of the schedule
+fn validate_vesting_transfer_ft(metadata: VestingTransferFt, txn: &CreateTransaction) -> ValidationResult {
+    let mut error_messages = String::new();
 
     if txn.value <= 0 {
         error_messages += "Value must be greater than zero. ";
     }
 
+    if txn.to_address == ZERO_ADDRESS {
+        error_messages += "To address must not be the zero address. ";
+    }
+
+    if metadata.start.is_some_and(|start| start < 0) {
+        error_messages += "Start must not be negative. ";
+    }
+
+    if metadata.duration <= 0 {
+        error_messages += "Duration must be greater than zero. ";
+    }
+
+    if metadata.cliff < 0 || metadata.cliff > metadata.duration {
+        error_messages += "Cliff must be between zero and the duration. ";
+    }
+
+    if error_messages.is_empty() {
+        ValidationResult::Valid
+    } else {
+        ValidationResult::Invalid(error_messages)
+    }
+}
+
+// Should check which token is being claimed, the quantity is whatever has vested so no value is given
+fn validate_claim_vested_ft(txn: &CreateTransaction) -> ValidationResult {
+    let mut error_messages = String::new();
+
+    if txn.value != 0 {
+        error_messages += "Value must be zero. ";
+    }
+
     if error_messages.is_empty() {
         ValidationResult::Valid
     } else {
@@ -111,24 +174,36 @@ fn validate_transfer_ft(data: &Value, txn: &CreateTransaction) -> ValidationResu
 }
 
 pub fn validate_transaction_metadata(txn: &CreateTransaction) -> ValidationResult {
-    // check if data field contains a valid JSON
-    if let Some(data) = txn.data.as_ref() {
-        if data.is_object() {
-            // validate JSON fields
-            return match txn.transaction_type {
-                TransactionType::InitFt => validate_init_ft(data),
-                TransactionType::MintFt => validate_mint_ft(data, &txn),
-                TransactionType::BurnFt => validate_burn_ft(data, &txn),
-                TransactionType::TransferFt => validate_transfer_ft(data, &txn),
-                TransactionType::InitNft => ValidationResult::Invalid("InitNft is not supported.".to_string()),
-                TransactionType::MintNft => ValidationResult::Invalid("MintNft is not supported.".to_string()),
-                TransactionType::BurnNft => ValidationResult::Invalid("BurnNft is not supported.".to_string()),
-                TransactionType::TransferNft => ValidationResult::Invalid("TransferNft is not supported.".to_string()),
-                TransactionType::None => ValidationResult::Invalid("TransactionType 'None' is not valid.".to_string()),
-            };
-        }
-    }
-    ValidationResult::Invalid("Invalid JSON data field.".to_string())
+    if txn.fee < 0 {
+        return ValidationResult::Invalid("Fee must not be negative.".to_string());
+    }
+    if txn.nonce.is_some_and(|nonce| nonce < 0) {
+        return ValidationResult::Invalid("Nonce must not be negative.".to_string());
+    }
+    // parse the data into the metadata of the transaction type, then validate its fields
+    let data = txn.data.as_ref();
+    let result = match txn.transaction_type {
+        TransactionType::InitFt => parse_metadata(data).map(|metadata| validate_init_ft(metadata, txn)),
+        TransactionType::MintFt => parse_metadata::<MintFt>(data).map(|_| validate_mint_ft(txn)),
+        TransactionType::BurnFt => parse_metadata::<BurnFt>(data).map(|_| validate_burn_ft(txn)),
+        TransactionType::TransferFt => parse_metadata::<TransferFt>(data).map(|_| validate_transfer_ft(txn)),
+        TransactionType::MultiTransferFt => parse_metadata(data).map(|metadata| validate_multi_transfer_ft(metadata, txn)),
+        TransactionType::TransferOwnershipFt
+        | TransactionType::RenounceOwnershipFt
+        | TransactionType::GrantMinterFt
+        | TransactionType::RevokeMinterFt
+        | TransactionType::PauseFt
+        | TransactionType::UnpauseFt
+        | TransactionType::FreezeAccount
+        | TransactionType::UnfreezeAccount => parse_metadata::<AdministerFt>(data).map(|_| validate_administer_ft(txn)),
+        TransactionType::VestingTransferFt => parse_metadata(data).map(|metadata| validate_vesting_transfer_ft(metadata, txn)),
+        TransactionType::ClaimVestedFt => parse_metadata::<ClaimVestedFt>(data).map(|_| validate_claim_vested_ft(txn)),
+        TransactionType::InitNft => Ok(ValidationResult::Invalid("InitNft is not supported.".to_string())),
+        TransactionType::MintNft => Ok(ValidationResult::Invalid("MintNft is not supported.".to_string())),
+        TransactionType::BurnNft => Ok(ValidationResult::Invalid("BurnNft is not supported.".to_string())),
+        TransactionType::TransferNft => Ok(ValidationResult::Invalid("TransferNft is not supported.".to_string())),
+    };
+    result.unwrap_or_else(|e| ValidationResult::Invalid(e.message))
 }
 
 fn is_non_empty_string(input: &str) -> bool {
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::error::CommonError;
+use crate::domain::models::vesting::{CreateVestingSchedule, VestingSchedule, VestingStatus};
+use crate::domain::repositories::vesting::VestingRepository;
+use crate::domain::services::vesting::VestingService;
+use tracing::instrument;
+
+#[derive(Clone)]
+pub struct VestingServiceImpl {
+    pub repository: Arc<dyn VestingRepository>,
+}
+
+impl VestingServiceImpl {
+    pub fn new(repository: Arc<dyn VestingRepository>) -> Self {
+        VestingServiceImpl { repository }
+    }
+}
+
+#[async_trait]
+impl VestingService for VestingServiceImpl {
+    #[instrument(name = "vesting_service.create", skip_all, fields(beneficiary_address = %new_schedule.beneficiary_address, token_address = %new_schedule.token_address))]
+    async fn create(&self, new_schedule: CreateVestingSchedule) -> Result<VestingSchedule, CommonError> {
+        self.repository.create(&new_schedule).await.map_err(|e| -> CommonError { e.into() })
+    }
+
+    #[instrument(name = "vesting_service.list", skip_all, fields(beneficiary_address = %beneficiary_address))]
+    async fn list(&self, beneficiary_address: &str, token_address: Option<&str>) -> Result<Vec<VestingSchedule>, CommonError> {
+        self.repository.list(beneficiary_address, token_address).await.map_err(|e| -> CommonError { e.into() })
+    }
+
+    #[instrument(name = "vesting_service.update_claimed", skip_all, fields(schedule_id, claimed_amount))]
+    async fn update_claimed(&self, schedule_id: i32, claimed_amount: i64) -> Result<VestingSchedule, CommonError> {
+        self.repository.update_claimed(schedule_id, claimed_amount).await.map_err(|e| -> CommonError { e.into() })
+    }
+
+    #[instrument(name = "vesting_service.status", skip_all, fields(beneficiary_address = %beneficiary_address, block_number, timestamp))]
+    async fn status(&self, beneficiary_address: &str, block_number: i32, timestamp: i64) -> Result<Vec<VestingStatus>, CommonError> {
+        let schedules = self.list(beneficiary_address, None).await?;
+        Ok(schedules
+            .into_iter()
+            .map(|schedule| VestingStatus {
+                vested_amount: schedule.vested_amount(block_number, timestamp),
+                schedule,
+            })
+            .collect())
+    }
+}
@@ -9,6 +9,7 @@ use crate::domain::repositories::repository::ResultPaging;
 use crate::domain::repositories::wallet::WalletQueryParams;
 use crate::domain::repositories::wallet::WalletRepository;
 use crate::domain::services::wallet::WalletService;
+use tracing::instrument;
 
 #[derive(Clone)]
 pub struct WalletServiceImpl {
@@ -23,16 +24,24 @@ impl WalletServiceImpl {
 
 #[async_trait]
 impl WalletService for WalletServiceImpl {
+    #[instrument(name = "wallet_service.create_or_update", skip_all, fields(wallet_address = %wallet.address, token_address = %wallet.token_address))]
     async fn create_or_update(&self, wallet: Wallet) -> Result<Wallet, CommonError> {
         let created_block = self.repository.create_or_update(&wallet).await.map_err(|e| -> CommonError { e.into() })?;
 
         Ok(created_block)
     }
 
+    #[instrument(name = "wallet_service.create_or_update_many", skip_all, fields(count = wallets.len()))]
+    async fn create_or_update_many(&self, wallets: Vec<Wallet>) -> Result<Vec<Wallet>, CommonError> {
+        self.repository.create_or_update_many(&wallets).await.map_err(|e| -> CommonError { e.into() })
+    }
+
+    #[instrument(name = "wallet_service.get", skip_all, fields(wallet_address = %wallet_address, token_address = %token_address))]
     async fn get(&self, wallet_address: &str, token_address: &str) -> Result<Wallet, CommonError> {
         self.repository.get(wallet_address, token_address).await.map_err(|e| -> CommonError { e.into() })
     }
 
+    #[instrument(name = "wallet_service.list", skip_all, fields(params = ?params))]
     async fn list(&self, params: WalletQueryParams) -> Result<ResultPaging<Wallet>, CommonError> {
         self.repository.list(params).await.map_err(|e| -> CommonError { e.into() })
     }
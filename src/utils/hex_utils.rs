@@ -1,16 +1,17 @@
 use rand::Rng;
+use sha3::{Digest, Keccak256};
 
-pub fn generate_hex_address() -> String {
-    // Required addresses should be 20 bytes long
-    let mut rng = rand::thread_rng();
-    let mut address_bytes: [u8; 20] = [0; 20];
-
-    rng.fill(&mut address_bytes);
+use crate::domain::models::address::Address;
 
-    // Convert the bytes to a hexadecimal string
-    let hex_address = format!("0x{}", hex::encode(address_bytes));
+// Address of the token created by the transaction of the creator with the given nonce, known before it is mined
+pub fn derive_token_address(creator_address: &Address, nonce: i64) -> Address {
+    let mut hasher = Keccak256::new();
+    hasher.update(creator_address.as_bytes());
+    hasher.update(nonce.to_be_bytes());
+    let hash = hasher.finalize();
 
-    hex_address
+    // Required addresses should be 20 bytes long, the last ones of the hash
+    Address::from_bytes(hash[12..].try_into().unwrap())
 }
 
 pub fn generate_transaction_hash() -> String {
@@ -29,3 +30,9 @@ pub fn generate_transaction_hash() -> String {
 pub fn generate_block_hash() -> String {
     generate_transaction_hash()
 }
+
+pub fn keccak256_hex(bytes: &[u8]) -> String {
+    let mut hasher = Keccak256::new();
+    hasher.update(bytes);
+    format!("0x{}", hex::encode(hasher.finalize()))
+}